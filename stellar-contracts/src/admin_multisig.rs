@@ -1,25 +1,78 @@
 use soroban_sdk::{
     contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, IntoVal, String,
-    Vec,
+    Symbol, Val, Vec,
 };
 
+use crate::{Deadline, FeeConfig, OptionalDeadline, Pagination};
+
+/// Proposals live in persistent storage; every access tops their TTL back up to roughly
+/// 30 days (at ~5s per ledger) once it falls below a week.
+const PROPOSAL_TTL_THRESHOLD: u32 = 7 * 17_280;
+const PROPOSAL_TTL_EXTEND_TO: u32 = 30 * 17_280;
+
+/// Index slots one `list_proposals` page may visit; each costs two reads (id, proposal).
+const PROPOSAL_SCAN_LIMIT: u32 = Pagination::MAX_SCAN / 2;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AdminAction {
-    UpgradeContract(BytesN<32>),
+    /// Upgrade a managed contract (contract address, new wasm hash). Targeting this
+    /// contract upgrades the admin multisig itself; any other target must expose an
+    /// `upgrade` entrypoint authorized by this contract.
+    UpgradeContract(Address, BytesN<32>),
+    AddIssuer(Address),
     RemoveIssuer(Address),
-    UpdateConfig(u32, Vec<Address>, u32),
+    /// Replace the admin config (threshold, signers, proposal window, execution delay)
+    UpdateConfig(u32, Vec<Address>, Deadline, Deadline),
+    /// Point governed certificate actions at a different CertificateContract
+    SetCertificateContract(Address),
+    /// Hand the CertificateContract admin role to a new address
+    SetCertificateAdmin(Address),
+    /// Set the admin of a CRL contract (CRL address, new admin); the CRL must be
+    /// initialized with this contract as its issuer
+    SetCrlAdmin(Address, Address),
+    SetPaused(bool),
+    SetFeeConfig(FeeConfig),
+    /// Revoke any certificate (certificate id, reason)
+    ForceRevoke(String, String),
     Other(String),
+    /// Several actions approved together and applied in order; nested batches are
+    /// rejected and an upgrade may only appear as the final action
+    Batch(Vec<AdminAction>),
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AdminMultisigConfig {
+    pub threshold: u32,
+    pub signers: Vec<Address>,
+    /// How long a proposal stays open, in ledgers or seconds from its creation
+    pub proposal_window: Deadline,
+    /// How long an approved proposal waits before it can be executed, in ledgers or seconds;
+    /// a zero delay executes on approval
+    pub execution_delay: Deadline,
+}
+
+/// Config shape written before execution delays existed; the proposal window is in ledgers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyAdminMultisigConfig {
     pub threshold: u32,
     pub signers: Vec<Address>,
     pub proposal_window: u32,
 }
 
+impl From<LegacyAdminMultisigConfig> for AdminMultisigConfig {
+    fn from(legacy: LegacyAdminMultisigConfig) -> Self {
+        AdminMultisigConfig {
+            threshold: legacy.threshold,
+            signers: legacy.signers,
+            proposal_window: Deadline::Ledger(legacy.proposal_window),
+            execution_delay: Deadline::Ledger(0),
+        }
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AdminProposalStatus {
@@ -37,17 +90,50 @@ pub struct AdminProposal {
     pub action: AdminAction,
     pub proposer: Address,
     pub approvals: Vec<Address>,
+    pub rejections: Vec<Address>,
+    pub created_ledger: u32,
+    pub expires_at: Deadline,
+    pub executable_at: OptionalDeadline,
+    pub status: AdminProposalStatus,
+}
+
+/// Action shape of proposals stored before governance could target other contracts;
+/// upgrades always targeted the admin multisig itself.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LegacyAdminAction {
+    UpgradeContract(BytesN<32>),
+    RemoveIssuer(Address),
+    UpdateConfig(u32, Vec<Address>, u32),
+    Other(String),
+}
+
+/// Proposal shape stored in instance storage before proposals moved to persistent storage.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyAdminProposal {
+    pub id: String,
+    pub action: LegacyAdminAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
     pub created_ledger: u32,
     pub expires_at_ledger: u32,
     pub status: AdminProposalStatus,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposalPage {
+    pub data: Vec<AdminProposal>,
+    pub next_cursor: Option<u32>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProposalCreatedEvent {
     pub proposal_id: String,
     pub proposer: Address,
-    pub expires_at_ledger: u32,
+    pub expires_at: Deadline,
 }
 
 #[contracttype]
@@ -59,6 +145,15 @@ pub struct ProposalApprovedEvent {
     pub threshold: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalRejectedEvent {
+    pub proposal_id: String,
+    pub rejector: Address,
+    pub rejection_count: u32,
+    pub threshold: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProposalCanceledEvent {
@@ -66,6 +161,13 @@ pub struct ProposalCanceledEvent {
     pub proposer: Address,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificateContractChangedEvent {
+    pub previous: Option<Address>,
+    pub certificate_contract: Address,
+}
+
 #[contract]
 pub struct AdminMultisigContract;
 
@@ -75,32 +177,42 @@ impl AdminMultisigContract {
         env: Env,
         threshold: u32,
         signers: Vec<Address>,
-        proposal_window: u32,
+        proposal_window: Deadline,
+        execution_delay: Deadline,
+        certificate_contract: Option<Address>,
     ) {
-        Self::validate_config(&signers, threshold, proposal_window);
+        Self::validate_config(&signers, threshold, &proposal_window);
 
-        if env
-            .storage()
-            .instance()
-            .has(&AdminMultisigDataKey::AdminConfig)
+        let storage = env.storage().instance();
+        if storage.has(&AdminMultisigDataKey::DelayedAdminConfig)
+            || storage.has(&AdminMultisigDataKey::AdminConfig)
         {
             panic!("Admin multisig already initialized");
         }
 
-        env.storage().instance().set(
-            &AdminMultisigDataKey::AdminConfig,
+        Self::store_config(
+            &env,
             &AdminMultisigConfig {
                 threshold,
                 signers,
                 proposal_window,
+                execution_delay,
             },
         );
+
+        if let Some(certificate_contract) = certificate_contract {
+            Self::store_certificate_contract(&env, certificate_contract);
+        }
     }
 
     pub fn get_config(env: Env) -> AdminMultisigConfig {
-        env.storage()
-            .instance()
-            .get(&AdminMultisigDataKey::AdminConfig)
+        let storage = env.storage().instance();
+        if let Some(config) = storage.get(&AdminMultisigDataKey::DelayedAdminConfig) {
+            return config;
+        }
+        storage
+            .get::<_, LegacyAdminMultisigConfig>(&AdminMultisigDataKey::AdminConfig)
+            .map(AdminMultisigConfig::from)
             .expect("Admin multisig not initialized")
     }
 
@@ -114,32 +226,39 @@ impl AdminMultisigContract {
 
         let config = Self::get_config(env.clone());
         Self::require_signer(&config.signers, &proposer);
+        Self::validate_action(&action);
 
+        // Unmigrated proposals still sit in instance storage under the same key
         let proposal_key = AdminMultisigDataKey::AdminProposal(proposal_id.clone());
-        if env.storage().instance().has(&proposal_key) {
+        if env.storage().persistent().has(&proposal_key)
+            || env.storage().instance().has(&proposal_key)
+        {
             panic!("Proposal already exists");
         }
 
         let created_ledger = env.ledger().sequence();
-        let expires_at_ledger = created_ledger.saturating_add(config.proposal_window);
+        let expires_at = config.proposal_window.from_now(&env);
 
         let proposal = AdminProposal {
             id: proposal_id.clone(),
             action,
             proposer: proposer.clone(),
             approvals: Vec::new(&env),
+            rejections: Vec::new(&env),
             created_ledger,
-            expires_at_ledger,
+            expires_at: expires_at.clone(),
+            executable_at: OptionalDeadline::None,
             status: AdminProposalStatus::Pending,
         };
 
-        env.storage().instance().set(&proposal_key, &proposal);
+        Self::store_proposal(&env, &proposal);
+        Self::index_proposal(&env, &proposal_id);
         env.events().publish(
             (symbol_short!("proposal"), symbol_short!("created")),
             ProposalCreatedEvent {
                 proposal_id,
                 proposer,
-                expires_at_ledger,
+                expires_at,
             },
         );
 
@@ -152,21 +271,15 @@ impl AdminMultisigContract {
         let config = Self::get_config(env.clone());
         Self::require_signer(&config.signers, &approver);
 
-        let proposal_key = AdminMultisigDataKey::AdminProposal(proposal_id.clone());
-        let mut proposal: AdminProposal = env
-            .storage()
-            .instance()
-            .get(&proposal_key)
-            .expect("Proposal not found");
+        let mut proposal = Self::load_proposal(&env, &proposal_id);
 
         if proposal.status != AdminProposalStatus::Pending {
             panic!("Proposal is not pending");
         }
 
-        let current_ledger = env.ledger().sequence();
-        if current_ledger > proposal.expires_at_ledger {
+        if proposal.expires_at.has_passed(&env) {
             proposal.status = AdminProposalStatus::Expired;
-            env.storage().instance().set(&proposal_key, &proposal);
+            Self::store_proposal(&env, &proposal);
             return AdminProposalStatus::Expired;
         }
 
@@ -191,30 +304,92 @@ impl AdminMultisigContract {
             },
         );
 
-        let mut status = AdminProposalStatus::Pending;
         if approval_count >= config.threshold {
             proposal.status = AdminProposalStatus::Approved;
-            status = AdminProposalStatus::Approved;
+            proposal.executable_at = OptionalDeadline::Some(config.execution_delay.from_now(&env));
         }
 
-        env.storage().instance().set(&proposal_key, &proposal);
+        Self::store_proposal(&env, &proposal);
 
-        if status == AdminProposalStatus::Approved {
-            status = Self::execute_action(env, proposal_id);
+        // Without a cooling-off period the action runs as soon as it is approved
+        if proposal.status == AdminProposalStatus::Approved && config.execution_delay.is_zero() {
+            return Self::run_action(env, proposal_id);
         }
 
-        status
+        proposal.status
+    }
+
+    /// Vote against a proposal while it is pending or waiting out its execution delay.
+    /// A rejection withdraws the signer's approval; the proposal is rejected once the
+    /// remaining signers can no longer reach the threshold.
+    pub fn reject_action(env: Env, proposal_id: String, rejector: Address) -> AdminProposalStatus {
+        rejector.require_auth();
+
+        let config = Self::get_config(env.clone());
+        Self::require_signer(&config.signers, &rejector);
+
+        let mut proposal = Self::load_proposal(&env, &proposal_id);
+
+        if proposal.status != AdminProposalStatus::Pending
+            && proposal.status != AdminProposalStatus::Approved
+        {
+            panic!("Proposal is not open");
+        }
+
+        if proposal.rejections.contains(&rejector) {
+            panic!("Already rejected by this signer");
+        }
+
+        if let Some(index) = proposal.approvals.first_index_of(&rejector) {
+            proposal.approvals.remove(index);
+        }
+        proposal.rejections.push_back(rejector.clone());
+        let rejection_count = proposal.rejections.len();
+
+        env.events().publish(
+            (symbol_short!("proposal"), symbol_short!("rejected")),
+            ProposalRejectedEvent {
+                proposal_id,
+                rejector,
+                rejection_count,
+                threshold: config.threshold,
+            },
+        );
+
+        if config.signers.len().saturating_sub(rejection_count) < config.threshold {
+            proposal.status = AdminProposalStatus::Rejected;
+            proposal.executable_at = OptionalDeadline::None;
+        } else if proposal.approvals.len() < config.threshold {
+            proposal.status = AdminProposalStatus::Pending;
+            proposal.executable_at = OptionalDeadline::None;
+        }
+
+        Self::store_proposal(&env, &proposal);
+        proposal.status
+    }
+
+    /// Execute an approved proposal once its execution delay has passed (any signer can call)
+    pub fn execute_action(env: Env, proposal_id: String, executor: Address) -> AdminProposalStatus {
+        executor.require_auth();
+
+        let config = Self::get_config(env.clone());
+        Self::require_signer(&config.signers, &executor);
+
+        let proposal = Self::load_proposal(&env, &proposal_id);
+
+        if let OptionalDeadline::Some(executable_at) = &proposal.executable_at {
+            if !executable_at.is_reached(&env) {
+                panic!("Execution delay has not passed");
+            }
+        }
+
+        Self::run_action(env, proposal_id)
     }
 
     pub fn cancel_proposal(env: Env, proposal_id: String, proposer: Address) {
         proposer.require_auth();
 
-        let proposal_key = AdminMultisigDataKey::AdminProposal(proposal_id.clone());
-        let mut proposal: AdminProposal = env
-            .storage()
-            .instance()
-            .get(&proposal_key)
-            .expect("Proposal not found");
+        let mut proposal = Self::load_proposal(&env, &proposal_id);
 
         if proposal.proposer != proposer {
             panic!("Only proposer can cancel");
@@ -225,7 +400,7 @@ impl AdminMultisigContract {
         }
 
         proposal.status = AdminProposalStatus::Rejected;
-        env.storage().instance().set(&proposal_key, &proposal);
+        Self::store_proposal(&env, &proposal);
 
         env.events().publish(
             (symbol_short!("proposal"), symbol_short!("canceled")),
@@ -237,10 +412,111 @@ impl AdminMultisigContract {
     }
 
     pub fn get_proposal(env: Env, proposal_id: String) -> AdminProposal {
+        Self::load_proposal(&env, &proposal_id)
+    }
+
+    /// Total number of proposals ever created
+    pub fn get_proposal_count(env: Env) -> u32 {
         env.storage()
             .instance()
-            .get(&AdminMultisigDataKey::AdminProposal(proposal_id))
-            .expect("Proposal not found")
+            .get(&AdminMultisigDataKey::ProposalCount)
+            .unwrap_or(0)
+    }
+
+    /// List proposals in creation order, optionally filtered by status. `cursor` is the
+    /// creation index to resume from (0 for the first page).
+    pub fn list_proposals(
+        env: Env,
+        status_filter: Option<AdminProposalStatus>,
+        cursor: u32,
+        limit: u32,
+    ) -> AdminProposalPage {
+        if limit == 0 {
+            panic!("Pagination limit must be greater than zero");
+        }
+        let count = Self::get_proposal_count(env.clone());
+        let mut data = Vec::<AdminProposal>::new(&env);
+        let mut index = cursor;
+
+        while index < count {
+            if data.len() >= limit || index - cursor >= PROPOSAL_SCAN_LIMIT {
+                return AdminProposalPage {
+                    data,
+                    next_cursor: Some(index),
+                };
+            }
+
+            let key = AdminMultisigDataKey::ProposalIdAt(index);
+            if let Some(proposal_id) = env.storage().persistent().get::<_, String>(&key) {
+                if let Some(proposal) = Self::try_load_proposal(&env, &proposal_id) {
+                    if status_filter.is_none() || status_filter.as_ref() == Some(&proposal.status) {
+                        data.push_back(proposal);
+                    }
+                }
+            }
+            index += 1;
+        }
+
+        AdminProposalPage {
+            data,
+            next_cursor: None,
+        }
+    }
+
+    /// Move proposals left in instance storage by the pre-upgrade contract into the proposal
+    /// index (any admin signer can call). The old layout kept no list of ids, so callers pass
+    /// them in, e.g. from `proposal created` events; ids with no legacy entry are skipped.
+    /// Returns the number of proposals moved.
+    pub fn migrate_legacy_proposals(env: Env, caller: Address, proposal_ids: Vec<String>) -> u32 {
+        caller.require_auth();
+        let config = Self::get_config(env.clone());
+        Self::require_signer(&config.signers, &caller);
+
+        let mut moved = 0;
+        for proposal_id in proposal_ids.iter() {
+            let legacy_key = AdminMultisigDataKey::AdminProposal(proposal_id.clone());
+            let legacy: LegacyAdminProposal = match env.storage().instance().get(&legacy_key) {
+                Some(legacy) => legacy,
+                None => continue,
+            };
+            env.storage().instance().remove(&legacy_key);
+            if Self::try_load_proposal(&env, &proposal_id).is_some() {
+                continue;
+            }
+
+            let action = match legacy.action {
+                LegacyAdminAction::UpgradeContract(wasm_hash) => {
+                    AdminAction::UpgradeContract(env.current_contract_address(), wasm_hash)
+                }
+                LegacyAdminAction::RemoveIssuer(issuer) => AdminAction::RemoveIssuer(issuer),
+                LegacyAdminAction::UpdateConfig(threshold, signers, proposal_window) => {
+                    AdminAction::UpdateConfig(
+                        threshold,
+                        signers,
+                        Deadline::Ledger(proposal_window),
+                        Deadline::Ledger(0),
+                    )
+                }
+                LegacyAdminAction::Other(description) => AdminAction::Other(description),
+            };
+            Self::store_proposal(
+                &env,
+                &AdminProposal {
+                    id: proposal_id.clone(),
+                    action,
+                    proposer: legacy.proposer,
+                    approvals: legacy.approvals,
+                    rejections: Vec::new(&env),
+                    created_ledger: legacy.created_ledger,
+                    expires_at: Deadline::Ledger(legacy.expires_at_ledger),
+                    executable_at: OptionalDeadline::None,
+                    status: legacy.status,
+                },
+            );
+            Self::index_proposal(&env, &proposal_id);
+            moved += 1;
+        }
+        moved
     }
 
     pub fn is_issuer_removed(env: Env, issuer: Address) -> bool {
@@ -267,17 +543,6 @@ impl AdminMultisigContract {
         Self::approve_action(env, proposal_id, approver)
     }
 
-    pub fn set_certificate_contract(env: Env, signer: Address, certificate_contract: Address) {
-        signer.require_auth();
-
-        let config = Self::get_config(env.clone());
-        Self::require_signer(&config.signers, &signer);
-
-        env.storage()
-            .instance()
-            .set(&AdminMultisigDataKey::CertificateContractId, &certificate_contract);
-    }
-
     pub fn get_certificate_contract(env: Env) -> Address {
         env.storage()
             .instance()
@@ -285,62 +550,207 @@ impl AdminMultisigContract {
             .expect("Certificate contract not configured")
     }
 
-    fn execute_action(env: Env, proposal_id: String) -> AdminProposalStatus {
-        let proposal_key = AdminMultisigDataKey::AdminProposal(proposal_id.clone());
-        let mut proposal: AdminProposal = env
-            .storage()
-            .instance()
-            .get(&proposal_key)
-            .expect("Proposal not found");
+    fn run_action(env: Env, proposal_id: String) -> AdminProposalStatus {
+        let mut proposal = Self::load_proposal(&env, &proposal_id);
 
         if proposal.status != AdminProposalStatus::Approved {
             panic!("Proposal is not approved");
         }
 
-        match &proposal.action {
-            AdminAction::UpgradeContract(wasm_hash) => {
-                env.deployer()
-                    .update_current_contract_wasm(wasm_hash.clone());
+        Self::apply_action(env.clone(), proposal.action.clone());
+
+        proposal.status = AdminProposalStatus::Executed;
+        Self::store_proposal(&env, &proposal);
+        env.events().publish(
+            (symbol_short!("proposal"), symbol_short!("executed")),
+            proposal_id,
+        );
+
+        AdminProposalStatus::Executed
+    }
+
+    /// Applies a single action. A failing action panics, so a batch either applies in
+    /// full or reverts with the rest of the invocation.
+    fn apply_action(env: Env, action: AdminAction) {
+        match &action {
+            AdminAction::Batch(actions) => {
+                for action in actions.iter() {
+                    Self::apply_action(env.clone(), action);
+                }
+            }
+            AdminAction::UpgradeContract(contract, wasm_hash) => {
+                if *contract == env.current_contract_address() {
+                    env.deployer()
+                        .update_current_contract_wasm(wasm_hash.clone());
+                } else {
+                    let _: () = env.invoke_contract(
+                        contract,
+                        &Symbol::new(&env, "upgrade"),
+                        soroban_sdk::vec![&env, wasm_hash.clone().into_val(&env)],
+                    );
+                }
+            }
+            AdminAction::AddIssuer(issuer) => {
+                env.storage()
+                    .instance()
+                    .remove(&AdminMultisigDataKey::RemovedIssuer(issuer.clone()));
+                Self::invoke_certificate_contract(
+                    &env,
+                    "add_issuer",
+                    soroban_sdk::vec![&env, issuer.clone().into_val(&env)],
+                );
             }
             AdminAction::RemoveIssuer(issuer) => {
                 env.storage()
                     .instance()
                     .set(&AdminMultisigDataKey::RemovedIssuer(issuer.clone()), &true);
-
-                let certificate_contract: Address = env
-                    .storage()
-                    .instance()
-                    .get(&AdminMultisigDataKey::CertificateContractId)
-                    .expect("Certificate contract not configured");
-
-                let _: () = env.invoke_contract(
-                    &certificate_contract,
-                    &soroban_sdk::Symbol::new(&env, "remove_issuer"),
+                Self::invoke_certificate_contract(
+                    &env,
+                    "remove_issuer",
                     soroban_sdk::vec![&env, issuer.clone().into_val(&env)],
                 );
             }
-            AdminAction::UpdateConfig(threshold, signers, proposal_window) => {
-                Self::validate_config(signers, *threshold, *proposal_window);
-                env.storage().instance().set(
-                    &AdminMultisigDataKey::AdminConfig,
+            AdminAction::UpdateConfig(threshold, signers, proposal_window, execution_delay) => {
+                Self::validate_config(signers, *threshold, proposal_window);
+                Self::store_config(
+                    &env,
                     &AdminMultisigConfig {
                         threshold: *threshold,
                         signers: signers.clone(),
-                        proposal_window: *proposal_window,
+                        proposal_window: proposal_window.clone(),
+                        execution_delay: execution_delay.clone(),
                     },
                 );
             }
+            AdminAction::SetCertificateContract(certificate_contract) => {
+                Self::store_certificate_contract(&env, certificate_contract.clone());
+            }
+            AdminAction::SetCertificateAdmin(new_admin) => {
+                Self::invoke_certificate_contract(
+                    &env,
+                    "set_admin",
+                    soroban_sdk::vec![&env, new_admin.clone().into_val(&env)],
+                );
+            }
+            AdminAction::SetCrlAdmin(crl_contract, crl_admin) => {
+                let _: () = env.invoke_contract(
+                    crl_contract,
+                    &Symbol::new(&env, "set_admin"),
+                    soroban_sdk::vec![&env, crl_admin.clone().into_val(&env)],
+                );
+            }
+            AdminAction::SetPaused(paused) => {
+                Self::invoke_certificate_contract(
+                    &env,
+                    "set_paused",
+                    soroban_sdk::vec![
+                        &env,
+                        env.current_contract_address().into_val(&env),
+                        paused.into_val(&env),
+                    ],
+                );
+            }
+            AdminAction::SetFeeConfig(fee_config) => {
+                Self::invoke_certificate_contract(
+                    &env,
+                    "set_fee_config",
+                    soroban_sdk::vec![&env, fee_config.clone().into_val(&env)],
+                );
+            }
+            AdminAction::ForceRevoke(certificate_id, reason) => {
+                Self::invoke_certificate_contract(
+                    &env,
+                    "force_revoke_certificate",
+                    soroban_sdk::vec![
+                        &env,
+                        certificate_id.clone().into_val(&env),
+                        reason.clone().into_val(&env),
+                    ],
+                );
+            }
             AdminAction::Other(_) => {}
         }
+    }
+
+    fn validate_action(action: &AdminAction) {
+        if let AdminAction::Batch(actions) = action {
+            if actions.is_empty() {
+                panic!("Batch must contain at least one action");
+            }
+
+            let last_index = actions.len() - 1;
+            for (index, batched) in actions.iter().enumerate() {
+                match batched {
+                    AdminAction::Batch(_) => panic!("Nested batches are not allowed"),
+                    AdminAction::UpgradeContract(_, _) if index as u32 != last_index => {
+                        panic!("Upgrades are only allowed as the last action of a batch")
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
 
-        proposal.status = AdminProposalStatus::Executed;
-        env.storage().instance().set(&proposal_key, &proposal);
+    fn store_certificate_contract(env: &Env, certificate_contract: Address) {
+        let previous: Option<Address> = env
+            .storage()
+            .instance()
+            .get(&AdminMultisigDataKey::CertificateContractId);
+        env.storage().instance().set(
+            &AdminMultisigDataKey::CertificateContractId,
+            &certificate_contract,
+        );
         env.events().publish(
-            (symbol_short!("proposal"), symbol_short!("executed")),
-            proposal_id,
+            (symbol_short!("cert_ctr"), symbol_short!("changed")),
+            CertificateContractChangedEvent {
+                previous,
+                certificate_contract,
+            },
         );
+    }
 
-        AdminProposalStatus::Executed
+    fn invoke_certificate_contract(env: &Env, function: &str, args: Vec<Val>) {
+        let certificate_contract: Address = env
+            .storage()
+            .instance()
+            .get(&AdminMultisigDataKey::CertificateContractId)
+            .expect("Certificate contract not configured");
+
+        let _: () = env.invoke_contract(&certificate_contract, &Symbol::new(env, function), args);
+    }
+
+    /// Reads leave the TTL alone so views stay read-only; `store_proposal` tops it up
+    fn try_load_proposal(env: &Env, proposal_id: &String) -> Option<AdminProposal> {
+        env.storage()
+            .persistent()
+            .get(&AdminMultisigDataKey::AdminProposal(proposal_id.clone()))
+    }
+
+    fn load_proposal(env: &Env, proposal_id: &String) -> AdminProposal {
+        Self::try_load_proposal(env, proposal_id).expect("Proposal not found")
+    }
+
+    fn store_proposal(env: &Env, proposal: &AdminProposal) {
+        let key = AdminMultisigDataKey::AdminProposal(proposal.id.clone());
+        env.storage().persistent().set(&key, proposal);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PROPOSAL_TTL_THRESHOLD, PROPOSAL_TTL_EXTEND_TO);
+    }
+
+    /// Append a proposal id to the creation-order index walked by `list_proposals`
+    fn index_proposal(env: &Env, proposal_id: &String) {
+        let index = Self::get_proposal_count(env.clone());
+        let index_key = AdminMultisigDataKey::ProposalIdAt(index);
+        env.storage().persistent().set(&index_key, proposal_id);
+        env.storage().persistent().extend_ttl(
+            &index_key,
+            PROPOSAL_TTL_THRESHOLD,
+            PROPOSAL_TTL_EXTEND_TO,
+        );
+        env.storage()
+            .instance()
+            .set(&AdminMultisigDataKey::ProposalCount, &(index + 1));
     }
 
     fn require_signer(signers: &Vec<Address>, signer: &Address) {
@@ -349,23 +759,33 @@ impl AdminMultisigContract {
         }
     }
 
-    fn validate_config(signers: &Vec<Address>, threshold: u32, proposal_window: u32) {
+    fn validate_config(signers: &Vec<Address>, threshold: u32, proposal_window: &Deadline) {
         #[allow(clippy::unnecessary_cast)]
         if signers.is_empty() || threshold == 0 || threshold > signers.len() as u32 {
             panic!("Invalid admin multisig configuration");
         }
 
-        if proposal_window == 0 {
+        if proposal_window.is_zero() {
             panic!("Proposal window must be greater than zero");
         }
     }
+
+    fn store_config(env: &Env, config: &AdminMultisigConfig) {
+        let storage = env.storage().instance();
+        storage.set(&AdminMultisigDataKey::DelayedAdminConfig, config);
+        storage.remove(&AdminMultisigDataKey::AdminConfig);
+    }
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AdminMultisigDataKey {
+    /// Pre-delay config, read as `LegacyAdminMultisigConfig` until the next config update
     AdminConfig,
+    DelayedAdminConfig,
     AdminProposal(String),
+    ProposalCount,
+    ProposalIdAt(u32),
     CertificateContractId,
     RemovedIssuer(Address),
 }
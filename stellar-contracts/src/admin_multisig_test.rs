@@ -1,7 +1,19 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{storage::Persistent as _, Address as _, Events, Ledger},
+    Address, Bytes, Env, IntoVal, String, Vec,
+};
+
+// Smallest module the host accepts as contract code: the wasm header plus a
+// `contractenvmetav0` section declaring interface version 21.
+const UPGRADE_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x00, 0x1e, 0x11, b'c', b'o', b'n', b't', b'r',
+    b'a', b'c', b't', b'e', b'n', b'v', b'm', b'e', b't', b'a', b'v', b'0', 0, 0, 0, 0, 0, 0, 0,
+    21, 0, 0, 0, 0,
+];
 
 #[test]
 fn test_admin_multisig_flow() {
@@ -21,7 +33,13 @@ fn test_admin_multisig_flow() {
     env.mock_all_auths();
 
     // Initialize with 2-of-3 multisig and a 10-ledger proposal window.
-    client.init_admin_multisig(&2, &signers, &10);
+    client.init_admin_multisig(
+        &2,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Ledger(0),
+        &None,
+    );
 
     let proposal_id = String::from_str(&env, "prop-1");
     let action = AdminAction::Other(String::from_str(&env, "custom_action"));
@@ -30,7 +48,10 @@ fn test_admin_multisig_flow() {
 
     assert_eq!(proposal.status, AdminProposalStatus::Pending);
     assert_eq!(proposal.created_ledger, env.ledger().sequence());
-    assert_eq!(proposal.expires_at_ledger, env.ledger().sequence() + 10);
+    assert_eq!(
+        proposal.expires_at,
+        Deadline::Ledger(env.ledger().sequence() + 10)
+    );
 
     // Admin2 approves (now 1-of-2)
     let status1 = client.approve_action(&proposal_id, &admin2);
@@ -67,8 +88,13 @@ fn test_remove_issuer_action_executes_after_threshold() {
     certificate_client.initialize(&admin_multisig_contract_id);
     certificate_client.add_issuer(&issuer);
 
-    client.init_admin_multisig(&2, &signers, &5);
-    client.set_certificate_contract(&admin1, &certificate_contract_id);
+    client.init_admin_multisig(
+        &2,
+        &signers,
+        &Deadline::Ledger(5),
+        &Deadline::Ledger(0),
+        &Some(certificate_contract_id),
+    );
 
     let proposal_id = String::from_str(&env, "remove-issuer-1");
     let action = AdminAction::RemoveIssuer(issuer.clone());
@@ -89,6 +115,9 @@ fn test_remove_issuer_action_executes_after_threshold() {
         &owner,
         &String::from_str(&env, "ipfs://meta"),
         &None,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+        &String::from_str(&env, "diploma"),
     );
     assert!(issue_result.is_err());
 }
@@ -107,7 +136,13 @@ fn test_proposer_cannot_approve() {
     signers.push_back(admin2.clone());
 
     env.mock_all_auths();
-    client.init_admin_multisig(&2, &signers, &10);
+    client.init_admin_multisig(
+        &2,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Ledger(0),
+        &None,
+    );
 
     let proposal_id = String::from_str(&env, "prop-fail");
     let action = AdminAction::Other(String::from_str(&env, "fail_action"));
@@ -126,7 +161,13 @@ fn test_cancel_proposal() {
     let signers = Vec::from_array(&env, [admin1.clone()]);
 
     env.mock_all_auths();
-    client.init_admin_multisig(&1, &signers, &10);
+    client.init_admin_multisig(
+        &1,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Ledger(0),
+        &None,
+    );
 
     let proposal_id = String::from_str(&env, "prop-cancel");
     let action = AdminAction::Other(String::from_str(&env, "to_be_canceled"));
@@ -139,3 +180,807 @@ fn test_cancel_proposal() {
     let canceled_proposal = client.get_proposal(&proposal_id);
     assert_eq!(canceled_proposal.status, AdminProposalStatus::Rejected);
 }
+
+fn propose_and_execute(
+    env: &Env,
+    client: &AdminMultisigContractClient<'_>,
+    proposer: &Address,
+    approver: &Address,
+    proposal_id: &str,
+    action: AdminAction,
+) {
+    let proposal_id = String::from_str(env, proposal_id);
+    client.propose_action(&proposal_id, proposer, &action);
+    let status = client.approve_action(&proposal_id, approver);
+    assert_eq!(status, AdminProposalStatus::Executed);
+}
+
+#[test]
+fn test_governance_actions_reach_certificate_contract() {
+    let env = Env::default();
+    let admin_multisig_contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &admin_multisig_contract_id);
+    let certificate_contract_id = env.register_contract(None, CertificateContract);
+    let certificate_client = CertificateContractClient::new(&env, &certificate_contract_id);
+
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+
+    env.mock_all_auths();
+
+    certificate_client.initialize(&admin_multisig_contract_id);
+    client.init_admin_multisig(
+        &1,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Ledger(0),
+        &Some(certificate_contract_id),
+    );
+
+    propose_and_execute(
+        &env,
+        &client,
+        &admin1,
+        &admin2,
+        "add-issuer",
+        AdminAction::AddIssuer(issuer.clone()),
+    );
+    assert!(certificate_client.is_issuer(&issuer));
+
+    let cert_id = String::from_str(&env, "governed-cert");
+    certificate_client.issue_certificate(
+        &cert_id,
+        &issuer,
+        &owner,
+        &String::from_str(&env, "ipfs://meta"),
+        &None,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+        &String::from_str(&env, "diploma"),
+    );
+
+    propose_and_execute(
+        &env,
+        &client,
+        &admin1,
+        &admin2,
+        "set-fees",
+        AdminAction::SetFeeConfig(FeeConfig {
+            min_transfer_fee: 100,
+        }),
+    );
+    assert_eq!(certificate_client.get_fee_config().min_transfer_fee, 100);
+
+    propose_and_execute(
+        &env,
+        &client,
+        &admin1,
+        &admin2,
+        "force-revoke",
+        AdminAction::ForceRevoke(
+            cert_id.clone(),
+            String::from_str(&env, "fraudulent issuance"),
+        ),
+    );
+    assert_eq!(
+        certificate_client.get_certificate(&cert_id).unwrap().status,
+        CertificateStatus::Revoked
+    );
+
+    propose_and_execute(
+        &env,
+        &client,
+        &admin1,
+        &admin2,
+        "pause",
+        AdminAction::SetPaused(true),
+    );
+    assert!(certificate_client.is_paused());
+    let issue_result = certificate_client.try_issue_certificate(
+        &String::from_str(&env, "paused-cert"),
+        &issuer,
+        &owner,
+        &String::from_str(&env, "ipfs://meta"),
+        &None,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+        &String::from_str(&env, "diploma"),
+    );
+    assert!(issue_result.is_err());
+
+    let new_admin = Address::generate(&env);
+    propose_and_execute(
+        &env,
+        &client,
+        &admin1,
+        &admin2,
+        "rotate-admin",
+        AdminAction::SetCertificateAdmin(new_admin.clone()),
+    );
+    assert_eq!(certificate_client.get_admin(), new_admin);
+}
+
+#[test]
+fn test_set_crl_admin_action() {
+    let env = Env::default();
+    let admin_multisig_contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &admin_multisig_contract_id);
+    let crl_contract_id = env.register_contract(None, CRLContract);
+    let crl_client = CRLContractClient::new(&env, &crl_contract_id);
+
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let crl_admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+
+    env.mock_all_auths();
+
+    crl_client.initialize(&admin_multisig_contract_id, &Address::generate(&env));
+    client.init_admin_multisig(
+        &1,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Ledger(0),
+        &None,
+    );
+
+    propose_and_execute(
+        &env,
+        &client,
+        &admin1,
+        &admin2,
+        "crl-admin",
+        AdminAction::SetCrlAdmin(crl_contract_id.clone(), crl_admin),
+    );
+}
+
+#[test]
+fn test_upgrade_action_targets_certificate_contract() {
+    let env = Env::default();
+    let admin_multisig_contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &admin_multisig_contract_id);
+    let certificate_contract_id = env.register_contract(None, CertificateContract);
+    let certificate_client = CertificateContractClient::new(&env, &certificate_contract_id);
+
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+
+    env.mock_all_auths();
+
+    certificate_client.initialize(&admin_multisig_contract_id);
+    client.init_admin_multisig(
+        &1,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Ledger(0),
+        &None,
+    );
+    assert_eq!(certificate_client.get_version().version, 0);
+
+    let wasm_hash = env
+        .deployer()
+        .upload_contract_wasm(Bytes::from_slice(&env, UPGRADE_WASM));
+    propose_and_execute(
+        &env,
+        &client,
+        &admin1,
+        &admin2,
+        "upgrade-certificate",
+        AdminAction::UpgradeContract(certificate_contract_id.clone(), wasm_hash.clone()),
+    );
+
+    // The certificate contract now runs the new code, so read its version in place
+    let version = env.as_contract(&certificate_contract_id, || {
+        CertificateContract::get_version(env.clone())
+    });
+    assert_eq!(version.version, 1);
+    assert_eq!(version.last_wasm_hash, wasm_hash);
+}
+
+#[test]
+fn test_approved_proposal_waits_for_execution_delay() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &contract_id);
+
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+
+    env.mock_all_auths();
+    // The cooling-off period is measured in seconds, like the issuer time-lock
+    client.init_admin_multisig(
+        &1,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Timestamp(60 * 60),
+        &None,
+    );
+
+    let proposal_id = String::from_str(&env, "prop-delayed");
+    let action = AdminAction::Other(String::from_str(&env, "delayed_action"));
+    client.propose_action(&proposal_id, &admin1, &action);
+
+    let status = client.approve_action(&proposal_id, &admin2);
+    assert_eq!(status, AdminProposalStatus::Approved);
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!(
+        proposal.executable_at,
+        OptionalDeadline::Some(Deadline::Timestamp(env.ledger().timestamp() + 60 * 60))
+    );
+
+    // Still cooling off
+    env.ledger().with_mut(|li| li.timestamp += 60 * 60 - 1);
+    assert!(client.try_execute_action(&proposal_id, &admin1).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    let status = client.execute_action(&proposal_id, &admin1);
+    assert_eq!(status, AdminProposalStatus::Executed);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        AdminProposalStatus::Executed
+    );
+}
+
+#[test]
+fn test_reject_votes_veto_approved_proposal() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &contract_id);
+
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone(), admin2.clone(), admin3.clone()]);
+
+    env.mock_all_auths();
+    client.init_admin_multisig(
+        &2,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Ledger(5),
+        &None,
+    );
+
+    let proposal_id = String::from_str(&env, "prop-veto");
+    let action = AdminAction::Other(String::from_str(&env, "contested_action"));
+    client.propose_action(&proposal_id, &admin1, &action);
+    client.approve_action(&proposal_id, &admin2);
+    let status = client.approve_action(&proposal_id, &admin3);
+    assert_eq!(status, AdminProposalStatus::Approved);
+
+    // admin3 changes their mind during the cooling-off period
+    let status = client.reject_action(&proposal_id, &admin3);
+    assert_eq!(status, AdminProposalStatus::Pending);
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!(proposal.approvals.len(), 1);
+    assert_eq!(proposal.executable_at, OptionalDeadline::None);
+
+    // A second rejection makes the threshold unreachable
+    let status = client.reject_action(&proposal_id, &admin1);
+    assert_eq!(status, AdminProposalStatus::Rejected);
+
+    env.ledger().with_mut(|li| li.sequence_number += 5);
+    assert!(client.try_execute_action(&proposal_id, &admin2).is_err());
+}
+
+#[test]
+fn test_list_proposals_with_status_filter() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &contract_id);
+
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+
+    env.mock_all_auths();
+    client.init_admin_multisig(
+        &1,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Ledger(0),
+        &None,
+    );
+
+    for id in ["prop-a", "prop-b", "prop-c"] {
+        client.propose_action(
+            &String::from_str(&env, id),
+            &admin1,
+            &AdminAction::Other(String::from_str(&env, id)),
+        );
+    }
+    client.approve_action(&String::from_str(&env, "prop-b"), &admin2);
+    assert_eq!(client.get_proposal_count(), 3);
+
+    let first_page = client.list_proposals(&None, &0, &2);
+    assert_eq!(first_page.data.len(), 2);
+    assert_eq!(first_page.next_cursor, Some(2));
+    assert_eq!(
+        first_page.data.get(0).unwrap().id,
+        String::from_str(&env, "prop-a")
+    );
+
+    let second_page = client.list_proposals(&None, &2, &2);
+    assert_eq!(second_page.data.len(), 1);
+    assert_eq!(second_page.next_cursor, None);
+
+    // A zero limit would return the same cursor forever
+    assert!(client.try_list_proposals(&None, &0, &0).is_err());
+
+    let open = client.list_proposals(&Some(AdminProposalStatus::Pending), &0, &10);
+    assert_eq!(open.data.len(), 2);
+    let executed = client.list_proposals(&Some(AdminProposalStatus::Executed), &0, &10);
+    assert_eq!(executed.data.len(), 1);
+    assert_eq!(
+        executed.data.get(0).unwrap().id,
+        String::from_str(&env, "prop-b")
+    );
+}
+
+#[test]
+fn test_proposals_use_persistent_storage() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &contract_id);
+
+    let admin1 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone()]);
+
+    env.mock_all_auths();
+    client.init_admin_multisig(
+        &1,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Ledger(0),
+        &None,
+    );
+
+    let proposal_id = String::from_str(&env, "prop-stored");
+    client.propose_action(
+        &proposal_id,
+        &admin1,
+        &AdminAction::Other(String::from_str(&env, "stored")),
+    );
+
+    env.as_contract(&contract_id, || {
+        let key = AdminMultisigDataKey::AdminProposal(proposal_id.clone());
+        assert!(!env.storage().instance().has(&key));
+        assert!(env.storage().persistent().has(&key));
+        assert!(env.storage().persistent().get_ttl(&key) >= 7 * 17_280);
+    });
+}
+
+#[test]
+fn test_certificate_contract_changes_only_through_approved_action() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &contract_id);
+    let original_contract = env.register_contract(None, CertificateContract);
+    let replacement_contract = env.register_contract(None, CertificateContract);
+
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+
+    env.mock_all_auths();
+    client.init_admin_multisig(
+        &1,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Ledger(0),
+        &Some(original_contract.clone()),
+    );
+    assert_eq!(client.get_certificate_contract(), original_contract);
+
+    let proposal_id = String::from_str(&env, "repoint-cert");
+    client.propose_action(
+        &proposal_id,
+        &admin1,
+        &AdminAction::SetCertificateContract(replacement_contract.clone()),
+    );
+    // A single proposer cannot repoint the contract on their own
+    assert_eq!(client.get_certificate_contract(), original_contract);
+
+    client.approve_action(&proposal_id, &admin2);
+    assert_eq!(client.get_certificate_contract(), replacement_contract);
+
+    let changed_event = env
+        .events()
+        .all()
+        .iter()
+        .filter(|event| {
+            event.1 == (symbol_short!("cert_ctr"), symbol_short!("changed")).into_val(&env)
+        })
+        .last()
+        .unwrap();
+    let event: CertificateContractChangedEvent = changed_event.2.into_val(&env);
+    assert_eq!(
+        event,
+        CertificateContractChangedEvent {
+            previous: Some(original_contract),
+            certificate_contract: replacement_contract,
+        }
+    );
+}
+
+#[test]
+fn test_batch_actions_apply_atomically() {
+    let env = Env::default();
+    let admin_multisig_contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &admin_multisig_contract_id);
+    let certificate_contract_id = env.register_contract(None, CertificateContract);
+    let certificate_client = CertificateContractClient::new(&env, &certificate_contract_id);
+
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let issuer1 = Address::generate(&env);
+    let issuer2 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+
+    env.mock_all_auths();
+
+    certificate_client.initialize(&admin_multisig_contract_id);
+    client.init_admin_multisig(
+        &1,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Ledger(0),
+        &Some(certificate_contract_id),
+    );
+
+    propose_and_execute(
+        &env,
+        &client,
+        &admin1,
+        &admin2,
+        "onboard-batch",
+        AdminAction::Batch(Vec::from_array(
+            &env,
+            [
+                AdminAction::AddIssuer(issuer1.clone()),
+                AdminAction::AddIssuer(issuer2.clone()),
+                AdminAction::SetPaused(true),
+            ],
+        )),
+    );
+    assert!(certificate_client.is_issuer(&issuer1));
+    assert!(certificate_client.is_issuer(&issuer2));
+    assert!(certificate_client.is_paused());
+
+    // The revocation targets an unknown certificate, so the removal before it rolls back
+    let failing_id = String::from_str(&env, "failing-batch");
+    client.propose_action(
+        &failing_id,
+        &admin1,
+        &AdminAction::Batch(Vec::from_array(
+            &env,
+            [
+                AdminAction::RemoveIssuer(issuer1.clone()),
+                AdminAction::ForceRevoke(
+                    String::from_str(&env, "missing-cert"),
+                    String::from_str(&env, "compromised"),
+                ),
+            ],
+        )),
+    );
+    assert!(client.try_approve_action(&failing_id, &admin2).is_err());
+    assert!(certificate_client.is_issuer(&issuer1));
+    assert!(!client.is_issuer_removed(&issuer1));
+    assert_eq!(
+        client.get_proposal(&failing_id).status,
+        AdminProposalStatus::Pending
+    );
+}
+
+#[test]
+fn test_batch_validation_at_proposal_time() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &contract_id);
+
+    let admin1 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone()]);
+
+    env.mock_all_auths();
+    client.init_admin_multisig(
+        &1,
+        &signers,
+        &Deadline::Ledger(10),
+        &Deadline::Ledger(0),
+        &None,
+    );
+
+    let note = AdminAction::Other(String::from_str(&env, "note"));
+    let upgrade =
+        AdminAction::UpgradeContract(contract_id.clone(), BytesN::from_array(&env, &[0; 32]));
+
+    let nested = AdminAction::Batch(Vec::from_array(
+        &env,
+        [AdminAction::Batch(Vec::from_array(&env, [note.clone()]))],
+    ));
+    assert!(client
+        .try_propose_action(&String::from_str(&env, "nested"), &admin1, &nested)
+        .is_err());
+
+    let upgrade_mid_batch =
+        AdminAction::Batch(Vec::from_array(&env, [upgrade.clone(), note.clone()]));
+    assert!(client
+        .try_propose_action(
+            &String::from_str(&env, "mid-upgrade"),
+            &admin1,
+            &upgrade_mid_batch
+        )
+        .is_err());
+
+    let empty = AdminAction::Batch(Vec::new(&env));
+    assert!(client
+        .try_propose_action(&String::from_str(&env, "empty"), &admin1, &empty)
+        .is_err());
+
+    let upgrade_last = AdminAction::Batch(Vec::from_array(&env, [note, upgrade]));
+    client.propose_action(
+        &String::from_str(&env, "upgrade-last"),
+        &admin1,
+        &upgrade_last,
+    );
+}
+
+#[test]
+fn test_proposal_window_counted_in_seconds() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &contract_id);
+
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone(), admin2.clone(), admin3.clone()]);
+
+    env.mock_all_auths();
+    client.init_admin_multisig(
+        &3,
+        &signers,
+        &Deadline::days(1),
+        &Deadline::Ledger(0),
+        &None,
+    );
+
+    let proposal_id = String::from_str(&env, "timed-prop");
+    let proposal = client.propose_action(
+        &proposal_id,
+        &admin1,
+        &AdminAction::Other(String::from_str(&env, "timed")),
+    );
+    assert_eq!(
+        proposal.expires_at,
+        Deadline::Timestamp(env.ledger().timestamp() + 24 * 60 * 60)
+    );
+
+    // Ledgers alone do not expire a time-bounded proposal
+    env.ledger().with_mut(|li| li.sequence_number += 1_000);
+    assert_eq!(
+        client.approve_action(&proposal_id, &admin2),
+        AdminProposalStatus::Pending
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60 + 1);
+    assert_eq!(
+        client.approve_action(&proposal_id, &admin3),
+        AdminProposalStatus::Expired
+    );
+}
+
+#[test]
+fn test_legacy_admin_config_still_governs() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &contract_id);
+
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+
+    // Config as written before execution delays were introduced
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(
+            &AdminMultisigDataKey::AdminConfig,
+            &LegacyAdminMultisigConfig {
+                threshold: 1,
+                signers: signers.clone(),
+                proposal_window: 10,
+            },
+        );
+    });
+
+    env.mock_all_auths();
+    let config = client.get_config();
+    assert_eq!(config.proposal_window, Deadline::Ledger(10));
+    assert_eq!(config.execution_delay, Deadline::Ledger(0));
+
+    let proposal_id = String::from_str(&env, "legacy-prop");
+    let proposal = client.propose_action(
+        &proposal_id,
+        &admin1,
+        &AdminAction::Other(String::from_str(&env, "legacy")),
+    );
+    assert_eq!(
+        proposal.expires_at,
+        Deadline::Ledger(env.ledger().sequence() + 10)
+    );
+    assert_eq!(
+        client.approve_action(&proposal_id, &admin2),
+        AdminProposalStatus::Executed
+    );
+
+    // Re-initializing over a legacy config is still refused
+    assert!(client
+        .try_init_admin_multisig(
+            &1,
+            &signers,
+            &Deadline::Ledger(10),
+            &Deadline::Ledger(0),
+            &None,
+        )
+        .is_err());
+
+    // The next config update moves it to the new key
+    let update_id = String::from_str(&env, "legacy-update");
+    client.propose_action(
+        &update_id,
+        &admin1,
+        &AdminAction::UpdateConfig(
+            1,
+            signers.clone(),
+            Deadline::Ledger(20),
+            Deadline::Ledger(0),
+        ),
+    );
+    client.approve_action(&update_id, &admin2);
+    assert_eq!(client.get_config().proposal_window, Deadline::Ledger(20));
+    env.as_contract(&contract_id, || {
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&AdminMultisigDataKey::AdminConfig));
+    });
+}
+
+#[test]
+fn test_list_proposals_stops_after_scan_limit() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &contract_id);
+
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+
+    env.mock_all_auths();
+    client.init_admin_multisig(
+        &2,
+        &signers,
+        &Deadline::Ledger(100),
+        &Deadline::Ledger(0),
+        &None,
+    );
+
+    let scan_limit = Pagination::MAX_SCAN / 2;
+    let ids = [
+        "p00", "p01", "p02", "p03", "p04", "p05", "p06", "p07", "p08", "p09", "p10", "p11", "p12",
+        "p13", "p14", "p15", "p16", "p17",
+    ];
+    assert!(ids.len() as u32 > scan_limit);
+    for id in ids {
+        client.propose_action(
+            &String::from_str(&env, id),
+            &admin1,
+            &AdminAction::Other(String::from_str(&env, "noop")),
+        );
+    }
+    client.cancel_proposal(&String::from_str(&env, "p17"), &admin1);
+
+    // No match within the scan limit: an empty page that still points further on
+    let rejected = Some(AdminProposalStatus::Rejected);
+    let first = client.list_proposals(&rejected, &0, &10);
+    assert_eq!(first.data.len(), 0);
+    assert_eq!(first.next_cursor, Some(scan_limit));
+
+    let second = client.list_proposals(&rejected, &first.next_cursor.unwrap(), &10);
+    assert_eq!(second.data.len(), 1);
+    assert_eq!(
+        second.data.get(0).unwrap().id,
+        String::from_str(&env, "p17")
+    );
+    assert_eq!(second.next_cursor, None);
+}
+
+#[test]
+fn test_legacy_instance_proposals_migrate_to_index() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, AdminMultisigContract);
+    let client = AdminMultisigContractClient::new(&env, &contract_id);
+
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin1.clone(), admin2.clone(), admin3.clone()]);
+
+    env.mock_all_auths();
+    client.init_admin_multisig(
+        &2,
+        &signers,
+        &Deadline::Ledger(100),
+        &Deadline::Ledger(0),
+        &None,
+    );
+
+    // A proposal left in instance storage by the pre-upgrade contract
+    let proposal_id = String::from_str(&env, "legacy-config");
+    let new_signers = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(
+            &AdminMultisigDataKey::AdminProposal(proposal_id.clone()),
+            &LegacyAdminProposal {
+                id: proposal_id.clone(),
+                action: LegacyAdminAction::UpdateConfig(1, new_signers.clone(), 50),
+                proposer: admin1.clone(),
+                approvals: Vec::new(&env),
+                created_ledger: env.ledger().sequence(),
+                expires_at_ledger: env.ledger().sequence() + 100,
+                status: AdminProposalStatus::Pending,
+            },
+        );
+    });
+
+    // Its id stays reserved until it is migrated
+    assert!(client
+        .try_propose_action(
+            &proposal_id,
+            &admin2,
+            &AdminAction::Other(String::from_str(&env, "clash")),
+        )
+        .is_err());
+    assert!(client.try_get_proposal(&proposal_id).is_err());
+
+    let outsider = Address::generate(&env);
+    let ids = Vec::from_array(
+        &env,
+        [proposal_id.clone(), String::from_str(&env, "never-existed")],
+    );
+    assert!(client
+        .try_migrate_legacy_proposals(&outsider, &ids)
+        .is_err());
+    assert_eq!(client.migrate_legacy_proposals(&admin2, &ids), 1);
+    assert_eq!(client.migrate_legacy_proposals(&admin2, &ids), 0);
+
+    env.as_contract(&contract_id, || {
+        let key = AdminMultisigDataKey::AdminProposal(proposal_id.clone());
+        assert!(!env.storage().instance().has(&key));
+        assert!(env.storage().persistent().has(&key));
+    });
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!(
+        proposal.expires_at,
+        Deadline::Ledger(env.ledger().sequence() + 100)
+    );
+    assert_eq!(
+        client.list_proposals(&None, &0, &10).data,
+        Vec::from_array(&env, [proposal])
+    );
+
+    // The migrated proposal goes through the current approval flow
+    client.approve_action(&proposal_id, &admin2);
+    assert_eq!(
+        client.approve_action(&proposal_id, &admin3),
+        AdminProposalStatus::Executed
+    );
+    let config = client.get_config();
+    assert_eq!(config.threshold, 1);
+    assert_eq!(config.signers, new_signers);
+    assert_eq!(config.proposal_window, Deadline::Ledger(50));
+}
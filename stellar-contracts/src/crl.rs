@@ -235,6 +235,13 @@ impl CRLContract {
         env.storage().instance().set(&DataKey::Admin, &admin);
     }
 
+    /// Upgrade the contract WASM. Only callable by the CRL issuer.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let issuer = Self::get_issuer(&env);
+        issuer.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
     pub fn needs_update(env: Env) -> bool {
         env.ledger().timestamp() >= Self::get_crl_info_internal(&env).next_update
     }
@@ -1,7 +1,19 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String, Vec,
+};
+
+fn all_issuers(client: &CertificateContractClient<'_>) -> Vec<Address> {
+    client
+        .get_issuers(&Pagination {
+            cursor: 0,
+            limit: 100,
+        })
+        .data
+}
 
 #[test]
 fn test_issuer_management() {
@@ -18,7 +30,7 @@ fn test_issuer_management() {
 
     // Initial count should be 0
     assert_eq!(client.get_issuer_count(), 0);
-    assert_eq!(client.get_issuers().len(), 0);
+    assert_eq!(all_issuers(&client).len(), 0);
 
     // Add first issuer
     env.mock_all_auths();
@@ -28,7 +40,7 @@ fn test_issuer_management() {
     assert!(client.is_issuer(&issuer1));
     assert!(!client.is_issuer(&issuer2));
 
-    let issuers = client.get_issuers();
+    let issuers = all_issuers(&client);
     assert_eq!(issuers.len(), 1);
     assert_eq!(issuers.get(0).unwrap(), issuer1);
 
@@ -38,13 +50,13 @@ fn test_issuer_management() {
     assert_eq!(client.get_issuer_count(), 2);
     assert!(client.is_issuer(&issuer2));
 
-    let issuers = client.get_issuers();
+    let issuers = all_issuers(&client);
     assert_eq!(issuers.len(), 2);
     
     // Add issuer1 again (should not increment count or add to list)
     client.add_issuer(&issuer1);
     assert_eq!(client.get_issuer_count(), 2);
-    assert_eq!(client.get_issuers().len(), 2);
+    assert_eq!(all_issuers(&client).len(), 2);
 }
 
 #[test]
@@ -71,7 +83,7 @@ fn test_remove_issuer_updates_vec_and_count() {
     assert!(!client.is_issuer(&issuer1));
     assert!(client.is_issuer(&issuer2));
 
-    let issuers = client.get_issuers();
+    let issuers = all_issuers(&client);
     assert_eq!(issuers.len(), 1);
     assert_eq!(issuers.get(0).unwrap(), issuer2);
 }
@@ -98,7 +110,7 @@ fn test_remove_issuer_idempotent_on_missing_issuer() {
     client.remove_issuer(&ghost);
 
     assert_eq!(client.get_issuer_count(), 1);
-    assert_eq!(client.get_issuers().len(), 1);
+    assert_eq!(all_issuers(&client).len(), 1);
     assert!(client.is_issuer(&issuer1));
 }
 
@@ -126,8 +138,589 @@ fn test_remove_all_issuers_reaches_zero() {
     client.remove_issuer(&issuer3);
 
     assert_eq!(client.get_issuer_count(), 0);
-    assert_eq!(client.get_issuers().len(), 0);
+    assert_eq!(all_issuers(&client).len(), 0);
     assert!(!client.is_issuer(&issuer1));
     assert!(!client.is_issuer(&issuer2));
     assert!(!client.is_issuer(&issuer3));
 }
+
+#[test]
+fn test_issuer_profile_lifecycle() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+
+    client.initialize(&admin);
+    env.mock_all_auths();
+
+    assert_eq!(client.get_issuer_profile(&issuer), None);
+    client.add_issuer(&issuer);
+
+    let profile = client.get_issuer_profile(&issuer).unwrap();
+    assert_eq!(profile.address, issuer);
+    assert_eq!(profile.display_name, None);
+    assert_eq!(profile.accreditation_level, 0);
+
+    let logo_hash = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    client.update_issuer_profile(
+        &issuer,
+        &Some(String::from_str(&env, "Stellar University")),
+        &Some(String::from_str(&env, "did:web:university.example")),
+        &Some(String::from_str(&env, "https://university.example")),
+        &Some(logo_hash.clone()),
+    );
+    client.set_issuer_accreditation(&issuer, &2);
+
+    let profile = client.get_issuer_profile(&issuer).unwrap();
+    assert_eq!(
+        profile.display_name,
+        Some(String::from_str(&env, "Stellar University"))
+    );
+    assert_eq!(
+        profile.did,
+        Some(String::from_str(&env, "did:web:university.example"))
+    );
+    assert_eq!(profile.logo_hash, Some(logo_hash));
+    assert_eq!(profile.accreditation_level, 2);
+
+    // Re-adding an issuer keeps its existing profile
+    client.add_issuer(&issuer);
+    assert_eq!(
+        client
+            .get_issuer_profile(&issuer)
+            .unwrap()
+            .accreditation_level,
+        2
+    );
+
+    client.remove_issuer(&issuer);
+    assert_eq!(client.get_issuer_profile(&issuer), None);
+}
+
+#[test]
+#[should_panic(expected = "Address is not an authorized issuer")]
+fn test_unknown_issuer_cannot_update_profile() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.initialize(&admin);
+    env.mock_all_auths();
+
+    client.update_issuer_profile(
+        &stranger,
+        &Some(String::from_str(&env, "Impostor")),
+        &None,
+        &None,
+        &None,
+    );
+}
+
+#[test]
+fn test_delegate_issues_under_organization_identity() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let staff = Address::generate(&env);
+    let owner = Address::generate(&env);
+
+    client.initialize(&admin);
+    env.mock_all_auths();
+    client.add_issuer(&issuer);
+
+    let diploma = String::from_str(&env, "diploma");
+    client.add_delegate(
+        &issuer,
+        &staff,
+        &Vec::from_array(&env, [diploma.clone()]),
+        &None,
+        &Some(1),
+    );
+    assert_eq!(
+        client
+            .get_delegates(
+                &issuer,
+                &Pagination {
+                    cursor: 0,
+                    limit: 10,
+                },
+            )
+            .data,
+        Vec::from_array(&env, [staff.clone()])
+    );
+
+    let cert_id = String::from_str(&env, "delegated-cert");
+    client.issue_certificate_as_delegate(
+        &cert_id,
+        &issuer,
+        &staff,
+        &owner,
+        &String::from_str(&env, "ipfs://meta"),
+        &None,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+        &diploma,
+    );
+
+    let cert = client.get_certificate(&cert_id).unwrap();
+    assert_eq!(cert.issuer, issuer);
+    assert_eq!(cert.issued_by, Some(staff.clone()));
+    assert_eq!(cert.certificate_type, diploma);
+    assert_eq!(
+        client.get_delegate(&issuer, &staff).unwrap().issued_count,
+        1
+    );
+
+    // Quota of one is used up
+    let result = client.try_issue_certificate_as_delegate(
+        &String::from_str(&env, "delegated-cert-2"),
+        &issuer,
+        &staff,
+        &owner,
+        &String::from_str(&env, "ipfs://meta"),
+        &None,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+        &diploma,
+    );
+    assert!(result.is_err());
+
+    client.remove_delegate(&issuer, &staff);
+    assert_eq!(client.get_delegate(&issuer, &staff), None);
+    assert_eq!(client.get_delegate_count(&issuer), 0);
+}
+
+#[test]
+fn test_delegate_scope_limits_types_and_expiry() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let staff = Address::generate(&env);
+    let owner = Address::generate(&env);
+
+    client.initialize(&admin);
+    env.mock_all_auths();
+    client.add_issuer(&issuer);
+
+    let expiry = env.ledger().timestamp() + 1_000;
+    client.add_delegate(
+        &issuer,
+        &staff,
+        &Vec::from_array(&env, [String::from_str(&env, "badge")]),
+        &Some(expiry),
+        &None,
+    );
+
+    let issue = |id: &str, certificate_type: &str| {
+        client.try_issue_certificate_as_delegate(
+            &String::from_str(&env, id),
+            &issuer,
+            &staff,
+            &owner,
+            &String::from_str(&env, "ipfs://meta"),
+            &None,
+            &TransferPolicy::FreelyTransferable,
+            &None,
+            &String::from_str(&env, certificate_type),
+        )
+    };
+
+    assert!(issue("cert-diploma", "diploma").is_err());
+    assert!(issue("cert-badge", "badge").is_ok());
+
+    env.ledger().with_mut(|li| li.timestamp = expiry);
+    assert!(issue("cert-badge-late", "badge").is_err());
+}
+
+#[test]
+fn test_accreditation_hierarchy_and_cascading_revocation() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let root = Address::generate(&env);
+    let intermediate = Address::generate(&env);
+    let leaf = Address::generate(&env);
+    let owner = Address::generate(&env);
+
+    client.initialize(&admin);
+    env.mock_all_auths();
+    client.add_issuer(&root);
+
+    let diploma = String::from_str(&env, "diploma");
+    client.accredit_issuer(
+        &root,
+        &intermediate,
+        &Vec::from_array(&env, [diploma.clone()]),
+    );
+    client.accredit_issuer(
+        &intermediate,
+        &leaf,
+        &Vec::from_array(&env, [diploma.clone()]),
+    );
+    assert!(client.is_issuer(&leaf));
+    assert_eq!(
+        client.get_accreditation_chain(&leaf),
+        Vec::from_array(&env, [leaf.clone(), intermediate.clone(), root.clone()])
+    );
+
+    // A sub-issuer cannot widen the scope it was given
+    let result = client.try_accredit_issuer(
+        &intermediate,
+        &Address::generate(&env),
+        &Vec::from_array(&env, [String::from_str(&env, "badge")]),
+    );
+    assert!(result.is_err());
+
+    // Direct issuance is held to the accredited scope as well
+    let result = client.try_issue_certificate(
+        &String::from_str(&env, "leaf-badge"),
+        &leaf,
+        &owner,
+        &String::from_str(&env, "ipfs://meta"),
+        &None,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+        &String::from_str(&env, "badge"),
+    );
+    assert!(result.is_err());
+
+    let cert_id = String::from_str(&env, "leaf-cert");
+    client.issue_certificate(
+        &cert_id,
+        &leaf,
+        &owner,
+        &String::from_str(&env, "ipfs://meta"),
+        &None,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+        &diploma,
+    );
+    assert!(client.is_valid_with_chain(&cert_id));
+
+    client.revoke_accreditation(&root, &intermediate);
+    assert!(client.get_accreditation(&intermediate).unwrap().revoked);
+
+    // The leaf loses issuance authority and its chain no longer verifies
+    let result = client.try_issue_certificate(
+        &String::from_str(&env, "leaf-cert-2"),
+        &leaf,
+        &owner,
+        &String::from_str(&env, "ipfs://meta"),
+        &None,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+        &diploma,
+    );
+    assert!(result.is_err());
+    assert!(client.is_valid(&cert_id));
+    assert!(!client.is_valid_with_chain(&cert_id));
+}
+
+#[test]
+fn test_delegate_registry_pages_and_stays_dense() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let staff1 = Address::generate(&env);
+    let staff2 = Address::generate(&env);
+    let staff3 = Address::generate(&env);
+
+    client.initialize(&admin);
+    env.mock_all_auths();
+    client.add_issuer(&issuer);
+
+    for staff in [&staff1, &staff2, &staff3] {
+        client.add_delegate(&issuer, staff, &Vec::new(&env), &None, &None);
+    }
+    // Replacing a delegate's scope does not register it twice
+    client.add_delegate(&issuer, &staff2, &Vec::new(&env), &None, &Some(5));
+    assert_eq!(client.get_delegate_count(&issuer), 3);
+
+    let first_page = client.get_delegates(
+        &issuer,
+        &Pagination {
+            cursor: 0,
+            limit: 2,
+        },
+    );
+    assert_eq!(
+        first_page.data,
+        Vec::from_array(&env, [staff1.clone(), staff2.clone()])
+    );
+    assert_eq!(first_page.next_cursor, Some(2));
+
+    // The last delegate fills the removed slot
+    client.remove_delegate(&issuer, &staff1);
+    let delegates = client.get_delegates(
+        &issuer,
+        &Pagination {
+            cursor: 0,
+            limit: 10,
+        },
+    );
+    assert_eq!(delegates.data, Vec::from_array(&env, [staff3, staff2]));
+    assert_eq!(delegates.next_cursor, None);
+    assert_eq!(client.get_delegate_count(&issuer), 2);
+
+    // Removing an unknown delegate leaves the registry alone
+    client.remove_delegate(&issuer, &staff1);
+    assert_eq!(client.get_delegate_count(&issuer), 2);
+}
+
+#[test]
+fn test_legacy_issuer_flag_reads_as_profile() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let legacy = Address::generate(&env);
+
+    client.initialize(&admin);
+    env.mock_all_auths();
+
+    // Issuers added before profiles existed were stored as a bare flag
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Issuer(legacy.clone()), &true);
+    });
+    assert!(client.is_issuer(&legacy));
+
+    let profile = client.get_issuer_profile(&legacy).unwrap();
+    assert_eq!(profile.address, legacy);
+    assert_eq!(profile.display_name, None);
+    assert_eq!(profile.accreditation_level, 0);
+
+    client.set_issuer_accreditation(&legacy, &1);
+    client.update_issuer_profile(
+        &legacy,
+        &Some(String::from_str(&env, "Legacy College")),
+        &None,
+        &None,
+        &None,
+    );
+
+    // The flag has been rewritten as a full profile
+    let profile = client.get_issuer_profile(&legacy).unwrap();
+    assert_eq!(
+        profile.display_name,
+        Some(String::from_str(&env, "Legacy College"))
+    );
+    assert_eq!(profile.accreditation_level, 1);
+    env.as_contract(&contract_id, || {
+        let stored: IssuerProfile = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Issuer(legacy.clone()))
+            .unwrap();
+        assert_eq!(stored, profile);
+    });
+}
+
+#[test]
+fn test_issuer_registry_pages_and_stays_dense() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer1 = Address::generate(&env);
+    let issuer2 = Address::generate(&env);
+    let issuer3 = Address::generate(&env);
+
+    client.initialize(&admin);
+    env.mock_all_auths();
+
+    client.add_issuer(&issuer1);
+    client.add_issuer(&issuer2);
+    client.add_issuer(&issuer3);
+
+    let first_page = client.get_issuers(&Pagination {
+        cursor: 0,
+        limit: 2,
+    });
+    assert_eq!(
+        first_page.data,
+        Vec::from_array(&env, [issuer1.clone(), issuer2.clone()])
+    );
+    assert_eq!(first_page.next_cursor, Some(2));
+
+    let second_page = client.get_issuers(&Pagination {
+        cursor: 2,
+        limit: 2,
+    });
+    assert_eq!(second_page.data, Vec::from_array(&env, [issuer3.clone()]));
+    assert_eq!(second_page.next_cursor, None);
+
+    // A zero limit would return the same cursor forever
+    let result = client.try_get_issuers(&Pagination {
+        cursor: 0,
+        limit: 0,
+    });
+    assert!(result.is_err());
+
+    // The last issuer fills the removed slot
+    client.remove_issuer(&issuer1);
+    assert_eq!(
+        all_issuers(&client),
+        Vec::from_array(&env, [issuer3.clone(), issuer2.clone()])
+    );
+    assert_eq!(client.get_issuer_count(), 2);
+
+    client.add_issuer(&issuer1);
+    client.remove_issuer(&issuer3);
+    assert_eq!(
+        all_issuers(&client),
+        Vec::from_array(&env, [issuer1, issuer2])
+    );
+    assert_eq!(client.get_issuer_count(), 2);
+}
+
+#[test]
+fn test_issuer_removal_while_paging_can_skip_moved_entry() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer1 = Address::generate(&env);
+    let issuer2 = Address::generate(&env);
+    let issuer3 = Address::generate(&env);
+
+    client.initialize(&admin);
+    env.mock_all_auths();
+    for issuer in [&issuer1, &issuer2, &issuer3] {
+        client.add_issuer(issuer);
+    }
+
+    let first_page = client.get_issuers(&Pagination {
+        cursor: 0,
+        limit: 2,
+    });
+    assert_eq!(first_page.next_cursor, Some(2));
+    let count_before = client.get_issuer_count();
+
+    // issuer3 moves into the slot the client has already read
+    client.remove_issuer(&issuer1);
+    let second_page = client.get_issuers(&Pagination {
+        cursor: first_page.next_cursor.unwrap(),
+        limit: 2,
+    });
+    assert_eq!(second_page.data.len(), 0);
+    assert!(!second_page.data.contains(&issuer3));
+
+    // The changed count tells the client to start over
+    assert_ne!(client.get_issuer_count(), count_before);
+    assert_eq!(
+        all_issuers(&client),
+        Vec::from_array(&env, [issuer3, issuer2])
+    );
+}
+
+#[test]
+fn test_legacy_issuer_list_migrates_in_batches() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer1 = Address::generate(&env);
+    let issuer2 = Address::generate(&env);
+    let issuer3 = Address::generate(&env);
+    let issuer4 = Address::generate(&env);
+
+    client.initialize(&admin);
+    env.mock_all_auths();
+
+    // State written before the indexed registry existed
+    let legacy = Vec::from_array(&env, [issuer1.clone(), issuer2.clone(), issuer3.clone()]);
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Issuers, &legacy);
+        for issuer in legacy.iter() {
+            storage.set(&DataKey::Issuer(issuer), &true);
+        }
+        storage.set(&DataKey::IssuerCount, &3u32);
+    });
+
+    // Reads serve the legacy list without migrating it
+    assert_eq!(all_issuers(&client), legacy);
+    env.as_contract(&contract_id, || {
+        assert!(env.storage().persistent().has(&DataKey::Issuers));
+    });
+
+    // Registry writes wait for the migration
+    assert!(client.try_add_issuer(&issuer4).is_err());
+    assert!(client.try_remove_issuer(&issuer1).is_err());
+    assert!(client.try_migrate_issuer_registry(&0).is_err());
+
+    assert_eq!(client.migrate_issuer_registry(&2), 1);
+    assert_eq!(client.get_issuer_count(), 3);
+    assert_eq!(all_issuers(&client), legacy);
+    assert!(client.try_add_issuer(&issuer4).is_err());
+
+    assert_eq!(client.migrate_issuer_registry(&2), 0);
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&DataKey::Issuers));
+        assert!(!storage.has(&DataKey::IssuerMigrationCursor));
+    });
+    assert_eq!(all_issuers(&client), legacy);
+
+    // Removing a pre-upgrade issuer keeps the registry dense
+    client.remove_issuer(&issuer1);
+    assert_eq!(client.get_issuer_count(), 2);
+    client.add_issuer(&issuer4);
+    assert_eq!(
+        all_issuers(&client),
+        Vec::from_array(&env, [issuer3, issuer2, issuer4])
+    );
+
+    // Nothing left to migrate
+    assert_eq!(client.migrate_issuer_registry(&10), 0);
+}
+
+#[test]
+fn test_removing_unindexed_issuer_leaves_registry_alone() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let stray = Address::generate(&env);
+
+    client.initialize(&admin);
+    env.mock_all_auths();
+
+    // An issuer flag with no registry slot and a zero count
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Issuer(stray.clone()), &true);
+    });
+
+    client.remove_issuer(&stray);
+    assert!(!client.is_issuer(&stray));
+    assert_eq!(client.get_issuer_count(), 0);
+}
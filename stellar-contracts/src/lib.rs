@@ -1,5 +1,7 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, BytesN, Env, Map, String, TryFromVal, Val, Vec,
+};
 
 mod types;
 pub use types::*;
@@ -27,7 +29,11 @@ mod multisig_test;
 #[cfg(test)]
 mod issuer_test;
 #[cfg(test)]
+mod pause_test;
+#[cfg(test)]
 mod status_test;
+#[cfg(test)]
+mod transfer_test;
 
 #[contract]
 pub struct CertificateContract;
@@ -50,44 +56,266 @@ impl CertificateContract {
             .expect("Contract not initialized");
         admin.require_auth();
 
+        // Issuers added by the admin are roots of the accreditation hierarchy
+        env.storage()
+            .persistent()
+            .remove(&DataKey::IssuerAccreditation(issuer.clone()));
+        Self::register_issuer(&env, issuer);
+    }
+
+    /// Accredit a sub-issuer within a scope of certificate types (only the parent issuer can call)
+    pub fn accredit_issuer(env: Env, parent: Address, issuer: Address, scope: Vec<String>) {
+        parent.require_auth();
+        Self::require_active_issuer(&env, &parent);
+
+        if Self::is_issuer(env.clone(), issuer.clone()) {
+            panic!("Address is already an issuer");
+        }
+
+        let chain = Self::get_accreditation_chain(env.clone(), parent.clone());
+        if chain.len() >= MAX_ACCREDITATION_DEPTH {
+            panic!("Accreditation chain is too deep");
+        }
+
+        let parent_scope = Self::accredited_scope(&env, &parent);
+        if !parent_scope.is_empty() {
+            if scope.is_empty() {
+                panic!("Scope must be within the parent's scope");
+            }
+            for certificate_type in scope.iter() {
+                if !parent_scope.contains(&certificate_type) {
+                    panic!("Scope must be within the parent's scope");
+                }
+            }
+        }
+
+        env.storage().persistent().set(
+            &DataKey::IssuerAccreditation(issuer.clone()),
+            &IssuerAccreditation {
+                parent: parent.clone(),
+                scope,
+                accredited_at: env.ledger().timestamp(),
+                revoked: false,
+            },
+        );
+        Self::register_issuer(&env, issuer.clone());
+
+        env.events().publish(
+            (symbol_short!("accredit"), symbol_short!("granted"), parent),
+            issuer,
+        );
+    }
+
+    /// Revoke a sub-issuer's accreditation, which also cuts off every issuer below it
+    /// (only the accrediting parent can call)
+    pub fn revoke_accreditation(env: Env, parent: Address, issuer: Address) {
+        parent.require_auth();
+
+        let key = DataKey::IssuerAccreditation(issuer.clone());
+        let mut accreditation: IssuerAccreditation = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .expect("Issuer is not accredited by another issuer");
+        if accreditation.parent != parent {
+            panic!("Only the accrediting issuer can revoke accreditation");
+        }
+
+        accreditation.revoked = true;
+        env.storage().persistent().set(&key, &accreditation);
+
+        env.events().publish(
+            (symbol_short!("accredit"), symbol_short!("revoked"), parent),
+            issuer,
+        );
+    }
+
+    /// Get an issuer's accreditation, or `None` for a root issuer
+    pub fn get_accreditation(env: Env, issuer: Address) -> Option<IssuerAccreditation> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::IssuerAccreditation(issuer))
+    }
+
+    /// Get the chain of issuers from `issuer` up to its root, starting with `issuer`
+    pub fn get_accreditation_chain(env: Env, issuer: Address) -> Vec<Address> {
+        let mut chain = Vec::new(&env);
+        let mut current = issuer;
+        loop {
+            chain.push_back(current.clone());
+            match Self::get_accreditation(env.clone(), current) {
+                Some(accreditation) if chain.len() <= MAX_ACCREDITATION_DEPTH => {
+                    current = accreditation.parent;
+                }
+                _ => return chain,
+            }
+        }
+    }
+
+    /// Check a certificate's validity together with its issuer's accreditation chain
+    pub fn is_valid_with_chain(env: Env, id: String) -> bool {
+        match Self::get_certificate(env.clone(), id.clone()) {
+            Some(cert) => {
+                Self::is_valid(env.clone(), id) && Self::accreditation_intact(&env, &cert.issuer)
+            }
+            None => false,
+        }
+    }
+
+    fn register_issuer(env: &Env, issuer: Address) {
+        Self::require_issuer_registry_migrated(env);
         let key = DataKey::Issuer(issuer.clone());
         if !env.storage().persistent().has(&key) {
-            let count: u32 = env.storage().persistent().get(&DataKey::IssuerCount).unwrap_or(0);
-            env.storage().persistent().set(&DataKey::IssuerCount, &(count + 1));
+            let now = env.ledger().timestamp();
+            // A retired issuer coming back is active again; a compromised one stays flagged
+            if Self::get_issuer_status(env.clone(), issuer.clone()).status == IssuerStatus::Retired
+            {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::IssuerStatus(issuer.clone()));
+            }
+            env.storage().persistent().set(
+                &key,
+                &IssuerProfile {
+                    address: issuer.clone(),
+                    display_name: None,
+                    did: None,
+                    website: None,
+                    logo_hash: None,
+                    accreditation_level: 0,
+                    added_at: now,
+                    updated_at: now,
+                },
+            );
 
-            let mut issuers: Vec<Address> = env
+            // Append to the end of the registry; the count doubles as its length
+            let count: u32 = env
                 .storage()
                 .persistent()
-                .get(&DataKey::Issuers)
-                .unwrap_or(Vec::new(&env));
-            issuers.push_back(issuer.clone());
-            env.storage().persistent().set(&DataKey::Issuers, &issuers);
+                .get(&DataKey::IssuerCount)
+                .unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&DataKey::IssuerAt(count), &issuer);
+            env.storage()
+                .persistent()
+                .set(&DataKey::IssuerIndex(issuer.clone()), &count);
+            env.storage()
+                .persistent()
+                .set(&DataKey::IssuerCount, &(count + 1));
         }
-        env.storage().persistent().set(&key, &true);
     }
 
     /// Check if an address is an authorized issuer
     pub fn is_issuer(env: Env, address: Address) -> bool {
-        env.storage()
+        env.storage().persistent().has(&DataKey::Issuer(address))
+    }
+
+    /// Get the profile of an authorized issuer
+    pub fn get_issuer_profile(env: Env, issuer: Address) -> Option<IssuerProfile> {
+        Self::load_issuer_profile(&env, &issuer)
+    }
+
+    /// Update the self-described fields of an issuer's profile (only the issuer can call)
+    pub fn update_issuer_profile(
+        env: Env,
+        issuer: Address,
+        display_name: Option<String>,
+        did: Option<String>,
+        website: Option<String>,
+        logo_hash: Option<String>,
+    ) {
+        issuer.require_auth();
+
+        let mut profile = Self::get_issuer_profile(env.clone(), issuer.clone())
+            .expect("Address is not an authorized issuer");
+        profile.display_name = display_name;
+        profile.did = did;
+        profile.website = website;
+        profile.logo_hash = logo_hash;
+        Self::store_issuer_profile(&env, profile);
+    }
+
+    /// Set an issuer's accreditation level (only admin can call)
+    pub fn set_issuer_accreditation(env: Env, issuer: Address, accreditation_level: u32) {
+        let admin: Address = env
+            .storage()
             .persistent()
-            .get(&DataKey::Issuer(address))
-            .unwrap_or(false)
+            .get(&DataKey::Admin)
+            .expect("Contract not initialized");
+        admin.require_auth();
+
+        let mut profile = Self::get_issuer_profile(env.clone(), issuer)
+            .expect("Address is not an authorized issuer");
+        profile.accreditation_level = accreditation_level;
+        Self::store_issuer_profile(&env, profile);
     }
 
     /// Get the total number of authorized issuers
     pub fn get_issuer_count(env: Env) -> u32 {
+        // Until the migration finishes, the legacy list is still the complete record
+        if let Some(legacy) = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<Address>>(&DataKey::Issuers)
+        {
+            return legacy.len();
+        }
         env.storage()
             .persistent()
             .get(&DataKey::IssuerCount)
             .unwrap_or(0)
     }
 
-    /// Get the list of all authorized issuers
-    pub fn get_issuers(env: Env) -> Vec<Address> {
-        env.storage()
+    /// Move up to `limit` issuers from the pre-registry issuer list into the indexed registry
+    /// (only admin can call). Call repeatedly until it returns 0, the number of legacy entries
+    /// left; adding or removing issuers is refused until then.
+    pub fn migrate_issuer_registry(env: Env, limit: u32) -> u32 {
+        let admin: Address = env
+            .storage()
             .persistent()
-            .get(&DataKey::Issuers)
-            .unwrap_or(Vec::new(&env))
+            .get(&DataKey::Admin)
+            .expect("Contract not initialized");
+        admin.require_auth();
+
+        if limit == 0 {
+            panic!("Migration limit must be greater than zero");
+        }
+
+        Self::migrate_legacy_issuers(&env, limit)
+    }
+
+    /// Get authorized issuers (cursor-paginated). Removing an issuer moves the last issuer
+    /// into the freed slot, so a client paging across a removal can skip the moved entry;
+    /// restart from cursor 0 if `get_issuer_count` changed between pages.
+    pub fn get_issuers(env: Env, pagination: Pagination) -> IssuerPaginatedResult {
+        pagination.require_limit();
+        let legacy: Option<Vec<Address>> = env.storage().persistent().get(&DataKey::Issuers);
+        let count = match &legacy {
+            Some(legacy) => legacy.len(),
+            None => Self::get_issuer_count(env.clone()),
+        };
+        let end = pagination
+            .cursor
+            .saturating_add(pagination.limit)
+            .min(count);
+
+        let mut data = Vec::new(&env);
+        for index in pagination.cursor..end {
+            let issuer = match &legacy {
+                Some(legacy) => legacy.get(index),
+                None => env.storage().persistent().get(&DataKey::IssuerAt(index)),
+            };
+            if let Some(issuer) = issuer {
+                data.push_back(issuer);
+            }
+        }
+
+        IssuerPaginatedResult {
+            data,
+            limit: pagination.limit,
+            next_cursor: if end < count { Some(end) } else { None },
+        }
     }
 
     /// Remove an authorized issuer (only admin can call)
@@ -99,41 +327,181 @@ impl CertificateContract {
             .expect("Contract not initialized");
         admin.require_auth();
 
+        Self::require_issuer_registry_migrated(&env);
         let key = DataKey::Issuer(issuer.clone());
 
-        // Only update the Vec and counter when the issuer was actually present.
+        // Only update the registry and counter when the issuer was actually present.
         if env.storage().persistent().has(&key) {
-            // Decrement the count (saturating so it never wraps below zero).
-            let count: u32 = env
-                .storage()
-                .persistent()
-                .get(&DataKey::IssuerCount)
-                .unwrap_or(0);
-            env.storage()
-                .persistent()
-                .set(&DataKey::IssuerCount, &count.saturating_sub(1));
+            // Move the last issuer into the freed slot so the registry stays dense.
+            let count = Self::get_issuer_count(env.clone());
+            let index_key = DataKey::IssuerIndex(issuer.clone());
+            let index: Option<u32> = env.storage().persistent().get(&index_key);
+            if let (Some(index), Some(last)) = (index, count.checked_sub(1)) {
+                if index < last {
+                    let moved: Address = env
+                        .storage()
+                        .persistent()
+                        .get(&DataKey::IssuerAt(last))
+                        .expect("Issuer missing from registry");
+                    env.storage()
+                        .persistent()
+                        .set(&DataKey::IssuerAt(index), &moved);
+                    env.storage()
+                        .persistent()
+                        .set(&DataKey::IssuerIndex(moved), &index);
+                }
+                env.storage().persistent().remove(&DataKey::IssuerAt(last));
+                env.storage().persistent().remove(&index_key);
+                env.storage().persistent().set(&DataKey::IssuerCount, &last);
+            }
 
-            // Rebuild the Issuers vec without the removed address.
-            let issuers: Vec<Address> = env
+            env.storage().persistent().remove(&key);
+
+            // Certificates issued so far stay valid unless the issuer is later marked compromised
+            if !env
                 .storage()
                 .persistent()
-                .get(&DataKey::Issuers)
-                .unwrap_or(Vec::new(&env));
-            let mut updated = Vec::new(&env);
-            for addr in issuers.iter() {
-                if addr != issuer {
-                    updated.push_back(addr);
-                }
+                .has(&DataKey::IssuerStatus(issuer.clone()))
+            {
+                Self::store_issuer_status(
+                    &env,
+                    issuer,
+                    IssuerStatus::Retired,
+                    env.ledger().timestamp(),
+                );
             }
-            env.storage()
+        }
+    }
+
+    /// Mark an issuer as active, retired or compromised from a given time (only admin can call)
+    pub fn set_issuer_status(env: Env, issuer: Address, status: IssuerStatus, effective_from: u64) {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .expect("Contract not initialized");
+        admin.require_auth();
+
+        Self::store_issuer_status(&env, issuer, status, effective_from);
+    }
+
+    /// Get an issuer's status; issuers without a recorded status are active
+    pub fn get_issuer_status(env: Env, issuer: Address) -> IssuerStatusRecord {
+        env.storage()
+            .persistent()
+            .get(&DataKey::IssuerStatus(issuer))
+            .unwrap_or(IssuerStatusRecord {
+                status: IssuerStatus::Active,
+                effective_from: 0,
+            })
+    }
+
+    /// Hand the admin role over to a new address (only admin can call)
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .expect("Contract not initialized");
+        admin.require_auth();
+
+        env.storage().persistent().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Get the current contract admin
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .expect("Contract not initialized")
+    }
+
+    /// Set or clear the guardian allowed to pause the contract (only admin can call)
+    pub fn set_guardian(env: Env, guardian: Option<Address>) {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .expect("Contract not initialized");
+        admin.require_auth();
+
+        match guardian {
+            Some(guardian) => env
+                .storage()
                 .persistent()
-                .set(&DataKey::Issuers, &updated);
+                .set(&DataKey::Guardian, &guardian),
+            None => env.storage().persistent().remove(&DataKey::Guardian),
+        }
+    }
+
+    /// Get the current guardian, if any
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Guardian)
+    }
+
+    /// Pause or resume issuance and transfers for every issuer (admin or guardian)
+    pub fn set_paused(env: Env, caller: Address, paused: bool) {
+        Self::require_admin_or_guardian(&env, &caller);
+
+        env.storage().persistent().set(&DataKey::Paused, &paused);
+        env.events()
+            .publish((symbol_short!("paused"), caller), paused);
+    }
+
+    /// Check whether the contract is paused
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
+    }
+
+    /// Pause or resume issuance and transfers for a single issuer (admin or guardian)
+    pub fn set_issuer_paused(env: Env, caller: Address, issuer: Address, paused: bool) {
+        Self::require_admin_or_guardian(&env, &caller);
 
+        let key = DataKey::IssuerPaused(issuer.clone());
+        if paused {
+            env.storage().persistent().set(&key, &true);
+        } else {
             env.storage().persistent().remove(&key);
         }
+        env.events()
+            .publish((symbol_short!("paused"), caller, issuer), paused);
+    }
+
+    /// Check whether a single issuer is paused (ignores the global flag)
+    pub fn is_issuer_paused(env: Env, issuer: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::IssuerPaused(issuer))
+            .unwrap_or(false)
+    }
+
+    /// Set the fee configuration (only admin can call)
+    pub fn set_fee_config(env: Env, config: FeeConfig) {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .expect("Contract not initialized");
+        admin.require_auth();
+
+        env.storage().persistent().set(&DataKey::FeeConfig, &config);
+    }
+
+    /// Get the fee configuration, with no minimum fee if none is set
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FeeConfig)
+            .unwrap_or(FeeConfig {
+                min_transfer_fee: 0,
+            })
     }
 
     /// Issue a new certificate
+    #[allow(clippy::too_many_arguments)]
     pub fn issue_certificate(
         env: Env,
         id: String,
@@ -141,18 +509,206 @@ impl CertificateContract {
         owner: Address,
         metadata_uri: String,
         expires_at: Option<u64>,
+        transfer_policy: TransferPolicy,
+        metadata_schema: Option<String>,
+        certificate_type: String,
     ) {
         issuer.require_auth();
 
-        // Authorization check
-        if !env
-            .storage()
+        Self::issue(
+            &env,
+            id,
+            issuer,
+            None,
+            owner,
+            metadata_uri,
+            expires_at,
+            transfer_policy,
+            metadata_schema,
+            certificate_type,
+        );
+    }
+
+    /// Issue a certificate under an organization's issuer identity using a delegate key
+    #[allow(clippy::too_many_arguments)]
+    pub fn issue_certificate_as_delegate(
+        env: Env,
+        id: String,
+        issuer: Address,
+        delegate: Address,
+        owner: Address,
+        metadata_uri: String,
+        expires_at: Option<u64>,
+        transfer_policy: TransferPolicy,
+        metadata_schema: Option<String>,
+        certificate_type: String,
+    ) {
+        delegate.require_auth();
+
+        Self::issue(
+            &env,
+            id,
+            issuer,
+            Some(delegate),
+            owner,
+            metadata_uri,
+            expires_at,
+            transfer_policy,
+            metadata_schema,
+            certificate_type,
+        );
+    }
+
+    /// Register or replace a delegate key and its scope (only the issuer can call)
+    pub fn add_delegate(
+        env: Env,
+        issuer: Address,
+        delegate: Address,
+        certificate_types: Vec<String>,
+        expires_at: Option<u64>,
+        quota: Option<u32>,
+    ) {
+        issuer.require_auth();
+        Self::require_active_issuer(&env, &issuer);
+
+        let key = DataKey::IssuerDelegate(issuer.clone(), delegate.clone());
+        let existing = env.storage().persistent().get::<_, IssuingDelegate>(&key);
+        if existing.is_none() {
+            // Append to the end of the issuer's delegate registry
+            let count = Self::get_delegate_count(env.clone(), issuer.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::IssuerDelegateAt(issuer.clone(), count), &delegate);
+            env.storage().persistent().set(
+                &DataKey::IssuerDelegateIndex(issuer.clone(), delegate.clone()),
+                &count,
+            );
+            env.storage()
+                .persistent()
+                .set(&DataKey::IssuerDelegateCount(issuer.clone()), &(count + 1));
+        }
+        let issued_count = existing.map(|existing| existing.issued_count).unwrap_or(0);
+        env.storage().persistent().set(
+            &key,
+            &IssuingDelegate {
+                delegate: delegate.clone(),
+                certificate_types,
+                expires_at,
+                quota,
+                issued_count,
+            },
+        );
+
+        env.events().publish(
+            (symbol_short!("delegate"), symbol_short!("added"), issuer),
+            delegate,
+        );
+    }
+
+    /// Revoke a delegate key (only the issuer can call)
+    pub fn remove_delegate(env: Env, issuer: Address, delegate: Address) {
+        issuer.require_auth();
+
+        env.storage()
             .persistent()
-            .get::<_, bool>(&DataKey::Issuer(issuer.clone()))
-            .unwrap_or(false)
-        {
-            panic!("Address is not an authorized issuer");
+            .remove(&DataKey::IssuerDelegate(issuer.clone(), delegate.clone()));
+
+        // Move the last delegate into the freed slot so the registry stays dense
+        let index_key = DataKey::IssuerDelegateIndex(issuer.clone(), delegate.clone());
+        if let Some(index) = env.storage().persistent().get::<_, u32>(&index_key) {
+            let last = Self::get_delegate_count(env.clone(), issuer.clone()).saturating_sub(1);
+            if index < last {
+                let moved: Address = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::IssuerDelegateAt(issuer.clone(), last))
+                    .expect("Delegate missing from registry");
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::IssuerDelegateAt(issuer.clone(), index), &moved);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::IssuerDelegateIndex(issuer.clone(), moved), &index);
+            }
+            env.storage()
+                .persistent()
+                .remove(&DataKey::IssuerDelegateAt(issuer.clone(), last));
+            env.storage().persistent().remove(&index_key);
+            env.storage()
+                .persistent()
+                .set(&DataKey::IssuerDelegateCount(issuer.clone()), &last);
+        }
+
+        env.events().publish(
+            (symbol_short!("delegate"), symbol_short!("removed"), issuer),
+            delegate,
+        );
+    }
+
+    /// Get a delegate's scope and issuance count
+    pub fn get_delegate(env: Env, issuer: Address, delegate: Address) -> Option<IssuingDelegate> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::IssuerDelegate(issuer, delegate))
+    }
+
+    /// Get the number of delegate keys registered by an issuer
+    pub fn get_delegate_count(env: Env, issuer: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::IssuerDelegateCount(issuer))
+            .unwrap_or(0)
+    }
+
+    /// Get a page of the delegate keys registered by an issuer (cursor-paginated; removals
+    /// reorder the registry the same way they do for `get_issuers`)
+    pub fn get_delegates(
+        env: Env,
+        issuer: Address,
+        pagination: Pagination,
+    ) -> DelegatePaginatedResult {
+        pagination.require_limit();
+        let count = Self::get_delegate_count(env.clone(), issuer.clone());
+        let end = pagination
+            .cursor
+            .saturating_add(pagination.limit)
+            .min(count);
+
+        let mut data = Vec::new(&env);
+        for index in pagination.cursor..end {
+            if let Some(delegate) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::IssuerDelegateAt(issuer.clone(), index))
+            {
+                data.push_back(delegate);
+            }
+        }
+
+        DelegatePaginatedResult {
+            data,
+            limit: pagination.limit,
+            next_cursor: if end < count { Some(end) } else { None },
         }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn issue(
+        env: &Env,
+        id: String,
+        issuer: Address,
+        issued_by: Option<Address>,
+        owner: Address,
+        metadata_uri: String,
+        expires_at: Option<u64>,
+        transfer_policy: TransferPolicy,
+        metadata_schema: Option<String>,
+        certificate_type: String,
+    ) {
+        Self::require_not_paused(env, &issuer);
+
+        // Authorization check
+        Self::require_active_issuer(env, &issuer);
 
         // Uniqueness check
         if env
@@ -163,6 +719,11 @@ impl CertificateContract {
             panic!("Certificate with this ID already exists");
         }
 
+        let accredited_scope = Self::accredited_scope(env, &issuer);
+        if !accredited_scope.is_empty() && !accredited_scope.contains(&certificate_type) {
+            panic!("Certificate type is outside the issuer's accreditation");
+        }
+
         let cert = Certificate {
             id: id.clone(),
             issuer: issuer.clone(),
@@ -180,16 +741,24 @@ impl CertificateContract {
             revocation_reason: None,
             status_reason: None,
             parent_certificate_id: None,
+            transfer_policy,
+            metadata_schema,
+            issued_by,
+            certificate_type,
         };
 
+        if let Some(delegate) = &cert.issued_by {
+            Self::charge_delegate(env, &cert, delegate);
+        }
+
         // Store the certificate
         env.storage()
             .persistent()
             .set(&DataKey::Certificate(id.clone()), &cert);
 
         // Track cert ID by issuer and owner
-        Self::append_cert_id(&env, DataKey::IssuerCertIds(issuer.clone()), id.clone());
-        Self::append_cert_id(&env, DataKey::OwnerCertIds(owner.clone()), id.clone());
+        Self::append_cert_id(env, DataKey::IssuerCertIds(issuer.clone()), id.clone());
+        Self::append_cert_id(env, DataKey::OwnerCertIds(owner.clone()), id.clone());
 
         // Emit and publish issuance event
         env.events().publish(
@@ -200,13 +769,27 @@ impl CertificateContract {
 
     /// Revoke an existing certificate (only the original issuer can revoke)
     pub fn revoke_certificate(env: Env, id: String, reason: String) {
-        let mut cert: Certificate = env
+        let cert: Certificate = Self::load_certificate(&env, &id).expect("Certificate not found");
+        cert.issuer.require_auth();
+
+        Self::revoke(&env, cert, id, reason);
+    }
+
+    /// Revoke any certificate regardless of issuer (only admin can call)
+    pub fn force_revoke_certificate(env: Env, id: String, reason: String) {
+        let admin: Address = env
             .storage()
             .persistent()
-            .get(&DataKey::Certificate(id.clone()))
-            .expect("Certificate not found");
-        cert.issuer.require_auth();
+            .get(&DataKey::Admin)
+            .expect("Contract not initialized");
+        admin.require_auth();
+
+        let cert: Certificate = Self::load_certificate(&env, &id).expect("Certificate not found");
 
+        Self::revoke(&env, cert, id, reason);
+    }
+
+    fn revoke(env: &Env, mut cert: Certificate, id: String, reason: String) {
         if cert.status == CertificateStatus::Revoked {
             panic!("Certificate is already revoked");
         }
@@ -224,6 +807,251 @@ impl CertificateContract {
         );
     }
 
+    /// Move the next `limit` entries of the pre-registry list into the indexed registry.
+    /// The old list was the only record of order, so slots are rebuilt from it: the count
+    /// restarts at zero with the first batch and the list is dropped after the last one.
+    fn migrate_legacy_issuers(env: &Env, limit: u32) -> u32 {
+        let legacy: Vec<Address> = match env.storage().persistent().get(&DataKey::Issuers) {
+            Some(legacy) => legacy,
+            None => return 0,
+        };
+
+        let cursor: Option<u32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IssuerMigrationCursor);
+        let start = cursor.unwrap_or(0);
+        let mut count: u32 = match cursor {
+            Some(_) => env
+                .storage()
+                .persistent()
+                .get(&DataKey::IssuerCount)
+                .unwrap_or(0),
+            None => 0,
+        };
+
+        let end = start.saturating_add(limit).min(legacy.len());
+        for index in start..end {
+            let issuer = legacy.get_unchecked(index);
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::IssuerIndex(issuer.clone()))
+            {
+                continue;
+            }
+            env.storage()
+                .persistent()
+                .set(&DataKey::IssuerAt(count), &issuer);
+            env.storage()
+                .persistent()
+                .set(&DataKey::IssuerIndex(issuer), &count);
+            count += 1;
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::IssuerCount, &count);
+
+        if end < legacy.len() {
+            env.storage()
+                .persistent()
+                .set(&DataKey::IssuerMigrationCursor, &end);
+        } else {
+            env.storage().persistent().remove(&DataKey::Issuers);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::IssuerMigrationCursor);
+        }
+        legacy.len() - end
+    }
+
+    fn require_issuer_registry_migrated(env: &Env) {
+        if env.storage().persistent().has(&DataKey::Issuers) {
+            panic!("Issuer registry migration pending");
+        }
+    }
+
+    /// Issuers added before profiles existed are stored as a plain `true` flag; read those
+    /// as an empty profile, which is written back the next time the profile changes
+    fn load_issuer_profile(env: &Env, issuer: &Address) -> Option<IssuerProfile> {
+        let entry: Val = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Issuer(issuer.clone()))?;
+        if let Ok(profile) = IssuerProfile::try_from_val(env, &entry) {
+            return Some(profile);
+        }
+        match bool::try_from_val(env, &entry) {
+            Ok(true) => Some(IssuerProfile {
+                address: issuer.clone(),
+                display_name: None,
+                did: None,
+                website: None,
+                logo_hash: None,
+                accreditation_level: 0,
+                added_at: 0,
+                updated_at: 0,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Certificates, transfers and requests written before the upgrade keep their legacy
+    /// shapes in storage; these loaders convert them on read
+    fn load_certificate(env: &Env, id: &String) -> Option<Certificate> {
+        let entry: Val = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Certificate(id.clone()))?;
+        Certificate::from_stored(env, entry)
+    }
+
+    fn load_transfer(env: &Env, transfer_id: &String) -> Option<CertificateTransfer> {
+        let entry: Val = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Transfer(transfer_id.clone()))?;
+        CertificateTransfer::from_stored(env, entry)
+    }
+
+    fn load_request(env: &Env, request_id: &String) -> Option<PendingRequest> {
+        let entry: Val = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingRequest(request_id.clone()))?;
+        PendingRequest::from_stored(env, entry)
+    }
+
+    fn store_issuer_profile(env: &Env, mut profile: IssuerProfile) {
+        profile.updated_at = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Issuer(profile.address.clone()), &profile);
+        env.events()
+            .publish((symbol_short!("issuer"), symbol_short!("profile")), profile);
+    }
+
+    fn store_issuer_status(env: &Env, issuer: Address, status: IssuerStatus, effective_from: u64) {
+        let record = IssuerStatusRecord {
+            status,
+            effective_from,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::IssuerStatus(issuer.clone()), &record);
+        env.events().publish(
+            (symbol_short!("issuer"), symbol_short!("status"), issuer),
+            record,
+        );
+    }
+
+    /// Whether the issuer's status still backs a certificate at the current ledger time
+    fn issuer_backs(env: &Env, cert: &Certificate) -> bool {
+        let record = Self::get_issuer_status(env.clone(), cert.issuer.clone());
+        match record.status {
+            IssuerStatus::Active => true,
+            IssuerStatus::Retired => cert.issued_at < record.effective_from,
+            IssuerStatus::Compromised => env.ledger().timestamp() < record.effective_from,
+        }
+    }
+
+    fn require_active_issuer(env: &Env, issuer: &Address) {
+        if !Self::is_issuer(env.clone(), issuer.clone()) {
+            panic!("Address is not an authorized issuer");
+        }
+        let record = Self::get_issuer_status(env.clone(), issuer.clone());
+        if record.status != IssuerStatus::Active
+            && env.ledger().timestamp() >= record.effective_from
+        {
+            panic!("Issuer is no longer active");
+        }
+        if !Self::accreditation_intact(env, issuer) {
+            panic!("Issuer accreditation has been revoked");
+        }
+    }
+
+    /// Whether every issuer from `issuer` up to its root is still registered and accredited
+    fn accreditation_intact(env: &Env, issuer: &Address) -> bool {
+        let chain = Self::get_accreditation_chain(env.clone(), issuer.clone());
+        for link in chain.iter() {
+            if !Self::is_issuer(env.clone(), link.clone()) {
+                return false;
+            }
+            if let Some(accreditation) = Self::get_accreditation(env.clone(), link) {
+                if accreditation.revoked {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Check a delegate-issued certificate against the delegate's scope, using the type
+    /// stored on the certificate, and count it against the delegate's quota
+    fn charge_delegate(env: &Env, cert: &Certificate, delegate: &Address) {
+        let key = DataKey::IssuerDelegate(cert.issuer.clone(), delegate.clone());
+        let mut scope: IssuingDelegate = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .expect("Address is not a delegate of this issuer");
+
+        if let Some(delegate_expiry) = scope.expires_at {
+            if env.ledger().timestamp() >= delegate_expiry {
+                panic!("Delegate authorization has expired");
+            }
+        }
+
+        if !scope.certificate_types.is_empty()
+            && !scope.certificate_types.contains(&cert.certificate_type)
+        {
+            panic!("Certificate type is outside the delegate's scope");
+        }
+
+        if let Some(quota) = scope.quota {
+            if scope.issued_count >= quota {
+                panic!("Delegate issuance quota exhausted");
+            }
+        }
+        scope.issued_count += 1;
+        env.storage().persistent().set(&key, &scope);
+    }
+
+    /// The certificate types an issuer may issue; empty means unrestricted
+    fn accredited_scope(env: &Env, issuer: &Address) -> Vec<String> {
+        for link in Self::get_accreditation_chain(env.clone(), issuer.clone()).iter() {
+            if let Some(accreditation) = Self::get_accreditation(env.clone(), link) {
+                if !accreditation.scope.is_empty() {
+                    return accreditation.scope;
+                }
+            }
+        }
+        Vec::new(env)
+    }
+
+    fn require_admin_or_guardian(env: &Env, caller: &Address) {
+        caller.require_auth();
+
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .expect("Contract not initialized");
+        let guardian: Option<Address> = env.storage().persistent().get(&DataKey::Guardian);
+        if *caller != admin && guardian.as_ref() != Some(caller) {
+            panic!("Only admin or guardian can pause");
+        }
+    }
+
+    fn require_not_paused(env: &Env, issuer: &Address) {
+        if Self::is_paused(env.clone()) {
+            panic!("Contract is paused");
+        }
+        if Self::is_issuer_paused(env.clone(), issuer.clone()) {
+            panic!("Issuer is paused");
+        }
+    }
+
     /// Check if a certificate exists
     pub fn certificate_exists(env: Env, id: String) -> bool {
         env.storage()
@@ -233,16 +1061,13 @@ impl CertificateContract {
 
     /// Get certificate details
     pub fn get_certificate(env: Env, id: String) -> Option<Certificate> {
-        env.storage().persistent().get(&DataKey::Certificate(id))
+        Self::load_certificate(&env, &id)
     }
 
     /// Suspend a certificate (temporarily disable with reason)
     pub fn suspend_certificate(env: Env, id: String, reason: String) {
-        let mut cert: Certificate = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Certificate(id.clone()))
-            .expect("Certificate not found");
+        let mut cert: Certificate =
+            Self::load_certificate(&env, &id).expect("Certificate not found");
         cert.issuer.require_auth();
 
         if cert.status != CertificateStatus::Active {
@@ -264,11 +1089,8 @@ impl CertificateContract {
 
     /// Reinstate a suspended certificate
     pub fn reinstate_certificate(env: Env, id: String, _reason: String) {
-        let mut cert: Certificate = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Certificate(id.clone()))
-            .expect("Certificate not found");
+        let mut cert: Certificate =
+            Self::load_certificate(&env, &id).expect("Certificate not found");
         cert.issuer.require_auth();
 
         if cert.status != CertificateStatus::Suspended {
@@ -289,11 +1111,8 @@ impl CertificateContract {
 
     /// Freeze a certificate
     pub fn freeze_certificate(env: Env, id: String) {
-        let mut cert: Certificate = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Certificate(id.clone()))
-            .expect("Certificate not found");
+        let mut cert: Certificate =
+            Self::load_certificate(&env, &id).expect("Certificate not found");
         cert.issuer.require_auth();
 
         if cert.status == CertificateStatus::Frozen {
@@ -314,11 +1133,8 @@ impl CertificateContract {
 
     /// Unfreeze a certificate
     pub fn unfreeze_certificate(env: Env, id: String) {
-        let mut cert: Certificate = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Certificate(id.clone()))
-            .expect("Certificate not found");
+        let mut cert: Certificate =
+            Self::load_certificate(&env, &id).expect("Certificate not found");
         cert.issuer.require_auth();
 
         if cert.status != CertificateStatus::Frozen {
@@ -339,11 +1155,7 @@ impl CertificateContract {
 
     /// Verify if a certificate is valid (active and not expired)
     pub fn is_valid(env: Env, id: String) -> bool {
-        if let Some(cert) = env
-            .storage()
-            .persistent()
-            .get::<_, Certificate>(&DataKey::Certificate(id))
-        {
+        if let Some(cert) = Self::load_certificate(&env, &id) {
             if cert.status != CertificateStatus::Active {
                 return false;
             }
@@ -352,7 +1164,7 @@ impl CertificateContract {
                     return false;
                 }
             }
-            true
+            Self::issuer_backs(&env, &cert)
         } else {
             false
         }
@@ -360,11 +1172,8 @@ impl CertificateContract {
 
     /// Update certificate metadata (requires issuer auth)
     pub fn update_certificate_metadata(env: Env, id: String, new_metadata_uri: String) {
-        let mut cert: Certificate = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Certificate(id.clone()))
-            .expect("Certificate not found");
+        let mut cert: Certificate =
+            Self::load_certificate(&env, &id).expect("Certificate not found");
         cert.issuer.require_auth();
 
         if cert.status != CertificateStatus::Active {
@@ -392,22 +1201,14 @@ impl CertificateContract {
     ) {
         issuer.require_auth();
 
-        // Verify issuer is authorized
-        if !env
-            .storage()
-            .persistent()
-            .get::<_, bool>(&DataKey::Issuer(issuer.clone()))
-            .unwrap_or(false)
-        {
-            panic!("Address is not an authorized issuer");
-        }
+        Self::require_not_paused(&env, &issuer);
+
+        // Verify issuer is authorized
+        Self::require_active_issuer(&env, &issuer);
 
         // Get original certificate
-        let original_cert: Certificate = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Certificate(old_id.clone()))
-            .expect("Original certificate not found");
+        let original_cert: Certificate =
+            Self::load_certificate(&env, &old_id).expect("Original certificate not found");
 
         // Verify issuer matches
         if original_cert.issuer != issuer {
@@ -443,6 +1244,10 @@ impl CertificateContract {
             revocation_reason: None,
             status_reason: None,
             parent_certificate_id: Some(old_id.clone()),
+            transfer_policy: original_cert.transfer_policy,
+            metadata_schema: original_cert.metadata_schema,
+            issued_by: None,
+            certificate_type: original_cert.certificate_type,
         };
 
         // Store new certificate
@@ -477,22 +1282,30 @@ impl CertificateContract {
         from_owner.require_auth();
 
         // Get certificate
-        let cert: Certificate = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Certificate(certificate_id.clone()))
-            .expect("Certificate not found");
+        let cert: Certificate =
+            Self::load_certificate(&env, &certificate_id).expect("Certificate not found");
 
         // Verify caller is the current owner
         if cert.owner != from_owner {
             panic!("Only certificate owner can initiate transfer");
         }
 
+        Self::require_not_paused(&env, &cert.issuer);
+
         // Verify certificate is active
         if cert.status != CertificateStatus::Active {
             panic!("Can only transfer active certificates");
         }
 
+        if transfer_fee < Self::get_fee_config(env.clone()).min_transfer_fee {
+            panic!("Transfer fee is below the configured minimum");
+        }
+
+        // Soulbound certificates can never change hands
+        if cert.transfer_policy == TransferPolicy::NonTransferable {
+            panic!("Certificate is non-transferable");
+        }
+
         // Check if transfer already exists
         if env
             .storage()
@@ -511,6 +1324,7 @@ impl CertificateContract {
             status: TransferStatus::Pending,
             initiated_at: env.ledger().timestamp(),
             accepted_at: None,
+            issuer_approved_at: None,
             completed_at: None,
             require_revocation,
             transfer_fee,
@@ -527,29 +1341,56 @@ impl CertificateContract {
         transfers.push_back(transfer_id.clone());
         env.storage()
             .persistent()
-            .set(&DataKey::CertificateTransfers(certificate_id), &transfers);
+            .set(&DataKey::CertificateTransfers(certificate_id.clone()), &transfers);
 
         // Add to pending transfers for new owner
         let mut pending = Self::get_pending_transfers(&env, to_owner.clone());
         pending.push_back(transfer_id.clone());
         env.storage()
             .persistent()
-            .set(&DataKey::PendingTransfers(to_owner), &pending);
+            .set(&DataKey::PendingTransfers(to_owner.clone()), &pending);
+
+        // Index the transfer for both parties
+        Self::append_transfer_id(
+            &env,
+            DataKey::IncomingTransfers(to_owner.clone()),
+            transfer_id.clone(),
+        );
+        Self::append_transfer_id(
+            &env,
+            DataKey::OutgoingTransfers(from_owner.clone()),
+            transfer_id.clone(),
+        );
 
         // Increment transfer count
         let count = Self::get_transfer_count(&env);
         env.storage().persistent().set(&DataKey::TransferCount, &(count + 1));
+
+        // Emit and publish transfer initiation event
+        env.events().publish(
+            (
+                symbol_short!("xfer_init"),
+                certificate_id.clone(),
+                from_owner.clone(),
+                to_owner.clone(),
+            ),
+            TransferInitiatedEvent {
+                transfer_id,
+                certificate_id,
+                from_owner,
+                to_owner,
+                require_revocation,
+                transfer_fee,
+            },
+        );
     }
 
     /// Accept a pending certificate transfer
     pub fn accept_transfer(env: Env, transfer_id: String, to_owner: Address) {
         to_owner.require_auth();
 
-        let mut transfer: CertificateTransfer = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Transfer(transfer_id.clone()))
-            .expect("Transfer not found");
+        let mut transfer: CertificateTransfer =
+            Self::load_transfer(&env, &transfer_id).expect("Transfer not found");
 
         // Verify caller is the intended recipient
         if transfer.to_owner != to_owner {
@@ -579,35 +1420,147 @@ impl CertificateContract {
         env.storage()
             .persistent()
             .set(&DataKey::PendingTransfers(to_owner), &updated_pending);
+
+        // Emit and publish transfer acceptance event
+        env.events().publish(
+            (
+                symbol_short!("xfer_acc"),
+                transfer.certificate_id.clone(),
+                transfer.from_owner.clone(),
+                transfer.to_owner.clone(),
+            ),
+            TransferAcceptedEvent {
+                transfer_id,
+                certificate_id: transfer.certificate_id,
+                from_owner: transfer.from_owner,
+                to_owner: transfer.to_owner,
+            },
+        );
+    }
+
+    /// Approve an accepted transfer of a certificate whose policy requires issuer approval
+    pub fn approve_transfer_as_issuer(env: Env, transfer_id: String, issuer: Address) {
+        issuer.require_auth();
+
+        let mut transfer: CertificateTransfer =
+            Self::load_transfer(&env, &transfer_id).expect("Transfer not found");
+
+        let cert: Certificate =
+            Self::load_certificate(&env, &transfer.certificate_id).expect("Certificate not found");
+
+        // Verify caller is the certificate issuer
+        if cert.issuer != issuer {
+            panic!("Only certificate issuer can approve transfer");
+        }
+
+        if cert.transfer_policy != TransferPolicy::IssuerApprovalRequired {
+            panic!("Transfer does not require issuer approval");
+        }
+
+        // Verify transfer has been accepted by the recipient
+        if transfer.status != TransferStatus::Accepted {
+            panic!("Transfer must be accepted before issuer approval");
+        }
+
+        transfer.status = TransferStatus::IssuerApproved;
+        transfer.issuer_approved_at = Some(env.ledger().timestamp());
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Transfer(transfer_id.clone()), &transfer);
+
+        // Emit and publish issuer approval event
+        env.events().publish(
+            (
+                symbol_short!("xfer_appr"),
+                transfer.certificate_id.clone(),
+                transfer.from_owner.clone(),
+                transfer.to_owner.clone(),
+            ),
+            TransferIssuerApprovedEvent {
+                transfer_id,
+                certificate_id: transfer.certificate_id,
+                from_owner: transfer.from_owner,
+                to_owner: transfer.to_owner,
+                issuer,
+            },
+        );
+    }
+
+    /// Deny an accepted transfer of a certificate whose policy requires issuer approval
+    pub fn deny_transfer_as_issuer(env: Env, transfer_id: String, issuer: Address) {
+        issuer.require_auth();
+
+        let mut transfer: CertificateTransfer =
+            Self::load_transfer(&env, &transfer_id).expect("Transfer not found");
+
+        let cert: Certificate =
+            Self::load_certificate(&env, &transfer.certificate_id).expect("Certificate not found");
+
+        // Verify caller is the certificate issuer
+        if cert.issuer != issuer {
+            panic!("Only certificate issuer can deny transfer");
+        }
+
+        if cert.transfer_policy != TransferPolicy::IssuerApprovalRequired {
+            panic!("Transfer does not require issuer approval");
+        }
+
+        if transfer.status != TransferStatus::Accepted {
+            panic!("Only accepted transfers can be denied");
+        }
+
+        transfer.status = TransferStatus::Rejected;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Transfer(transfer_id.clone()), &transfer);
+
+        // Emit and publish issuer denial event
+        env.events().publish(
+            (
+                symbol_short!("xfer_deny"),
+                transfer.certificate_id.clone(),
+                transfer.from_owner.clone(),
+                transfer.to_owner.clone(),
+            ),
+            TransferIssuerDeniedEvent {
+                transfer_id,
+                certificate_id: transfer.certificate_id,
+                from_owner: transfer.from_owner,
+                to_owner: transfer.to_owner,
+                issuer,
+            },
+        );
     }
 
     /// Complete a certificate transfer (requires original owner auth)
     pub fn complete_transfer(env: Env, transfer_id: String, from_owner: Address) {
         from_owner.require_auth();
 
-        let mut transfer: CertificateTransfer = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Transfer(transfer_id.clone()))
-            .expect("Transfer not found");
+        let mut transfer: CertificateTransfer =
+            Self::load_transfer(&env, &transfer_id).expect("Transfer not found");
 
         // Verify caller is the original owner
         if transfer.from_owner != from_owner {
             panic!("Only original owner can complete transfer");
         }
 
-        // Verify transfer is accepted
-        if transfer.status != TransferStatus::Accepted {
+        // Update certificate ownership
+        let mut cert: Certificate =
+            Self::load_certificate(&env, &transfer.certificate_id).expect("Certificate not found");
+
+        Self::require_not_paused(&env, &cert.issuer);
+
+        // Verify transfer is accepted (and approved by the issuer if the policy requires it)
+        if cert.transfer_policy == TransferPolicy::IssuerApprovalRequired {
+            if transfer.status != TransferStatus::IssuerApproved {
+                panic!("Transfer requires issuer approval before completion");
+            }
+        } else if transfer.status != TransferStatus::Accepted {
             panic!("Transfer must be accepted before completion");
         }
 
-        // Update certificate ownership
-        let mut cert: Certificate = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Certificate(transfer.certificate_id.clone()))
-            .expect("Certificate not found");
-
         cert.owner = transfer.to_owner.clone();
 
         // Revoke if required
@@ -637,24 +1590,38 @@ impl CertificateContract {
         env.storage()
             .persistent()
             .set(&DataKey::Transfer(transfer_id.clone()), &transfer);
+
+        // Emit and publish transfer completion event
+        env.events().publish(
+            (
+                symbol_short!("xfer_done"),
+                transfer.certificate_id.clone(),
+                transfer.from_owner.clone(),
+                transfer.to_owner.clone(),
+            ),
+            TransferCompletedEvent {
+                transfer_id,
+                certificate_id: transfer.certificate_id,
+                from_owner: transfer.from_owner,
+                to_owner: transfer.to_owner,
+                revoked: transfer.require_revocation,
+            },
+        );
     }
 
-    /// Reject a pending certificate transfer
+    /// Reject a certificate transfer that has not been completed yet
     pub fn reject_transfer(env: Env, transfer_id: String, to_owner: Address) {
         to_owner.require_auth();
 
-        let mut transfer: CertificateTransfer = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Transfer(transfer_id.clone()))
-            .expect("Transfer not found");
+        let mut transfer: CertificateTransfer =
+            Self::load_transfer(&env, &transfer_id).expect("Transfer not found");
 
         if transfer.to_owner != to_owner {
             panic!("Only intended recipient can reject transfer");
         }
 
-        if transfer.status != TransferStatus::Pending {
-            panic!("Transfer is not pending");
+        if !Self::transfer_in_progress(&transfer) {
+            panic!("Transfer is not in progress");
         }
 
         transfer.status = TransferStatus::Rejected;
@@ -674,24 +1641,37 @@ impl CertificateContract {
         env.storage()
             .persistent()
             .set(&DataKey::PendingTransfers(to_owner), &updated_pending);
+
+        // Emit and publish transfer rejection event
+        env.events().publish(
+            (
+                symbol_short!("xfer_rej"),
+                transfer.certificate_id.clone(),
+                transfer.from_owner.clone(),
+                transfer.to_owner.clone(),
+            ),
+            TransferRejectedEvent {
+                transfer_id,
+                certificate_id: transfer.certificate_id,
+                from_owner: transfer.from_owner,
+                to_owner: transfer.to_owner,
+            },
+        );
     }
 
-    /// Cancel a pending certificate transfer
+    /// Cancel a certificate transfer that has not been completed yet
     pub fn cancel_transfer(env: Env, transfer_id: String, from_owner: Address) {
         from_owner.require_auth();
 
-        let mut transfer: CertificateTransfer = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Transfer(transfer_id.clone()))
-            .expect("Transfer not found");
+        let mut transfer: CertificateTransfer =
+            Self::load_transfer(&env, &transfer_id).expect("Transfer not found");
 
         if transfer.from_owner != from_owner {
             panic!("Only initiator can cancel transfer");
         }
 
-        if transfer.status != TransferStatus::Pending {
-            panic!("Transfer is not pending");
+        if !Self::transfer_in_progress(&transfer) {
+            panic!("Transfer is not in progress");
         }
 
         transfer.status = TransferStatus::Cancelled;
@@ -710,7 +1690,31 @@ impl CertificateContract {
         }
         env.storage()
             .persistent()
-            .set(&DataKey::PendingTransfers(transfer.to_owner), &updated_pending);
+            .set(&DataKey::PendingTransfers(transfer.to_owner.clone()), &updated_pending);
+
+        // Emit and publish transfer cancellation event
+        env.events().publish(
+            (
+                symbol_short!("xfer_cncl"),
+                transfer.certificate_id.clone(),
+                transfer.from_owner.clone(),
+                transfer.to_owner.clone(),
+            ),
+            TransferCancelledEvent {
+                transfer_id,
+                certificate_id: transfer.certificate_id,
+                from_owner: transfer.from_owner,
+                to_owner: transfer.to_owner,
+            },
+        );
+    }
+
+    /// Whether a transfer can still be rejected or cancelled
+    fn transfer_in_progress(transfer: &CertificateTransfer) -> bool {
+        matches!(
+            transfer.status,
+            TransferStatus::Pending | TransferStatus::Accepted | TransferStatus::IssuerApproved
+        )
     }
 
     /// Get transfer history for a certificate
@@ -739,10 +1743,7 @@ impl CertificateContract {
 
     /// Get transfer details
     pub fn get_transfer(env: Env, transfer_id: String) -> CertificateTransfer {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Transfer(transfer_id))
-            .expect("Transfer not found")
+        Self::load_transfer(&env, &transfer_id).expect("Transfer not found")
     }
 
     /// Get transfer history for a certificate (public wrapper)
@@ -760,6 +1761,70 @@ impl CertificateContract {
         Self::get_transfer_count(&env)
     }
 
+    /// Get transfers sent to an address, optionally filtered by status (cursor-paginated)
+    pub fn get_incoming_transfers(
+        env: Env,
+        address: Address,
+        status: Option<TransferStatus>,
+        pagination: Pagination,
+    ) -> TransferPaginatedResult {
+        let ids = Self::get_transfer_ids(&env, DataKey::IncomingTransfers(address));
+        Self::paginate_transfers(&env, ids, status, pagination)
+    }
+
+    /// Get transfers initiated by an address, optionally filtered by status (cursor-paginated)
+    pub fn get_outgoing_transfers(
+        env: Env,
+        address: Address,
+        status: Option<TransferStatus>,
+        pagination: Pagination,
+    ) -> TransferPaginatedResult {
+        let ids = Self::get_transfer_ids(&env, DataKey::OutgoingTransfers(address));
+        Self::paginate_transfers(&env, ids, status, pagination)
+    }
+
+    /// Get the transfers of a certificate, optionally filtered by status (cursor-paginated)
+    pub fn get_certificate_transfers(
+        env: Env,
+        certificate_id: String,
+        status: Option<TransferStatus>,
+        pagination: Pagination,
+    ) -> TransferPaginatedResult {
+        let ids = Self::get_transfer_history(&env, certificate_id);
+        Self::paginate_transfers(&env, ids, status, pagination)
+    }
+
+    fn get_transfer_ids(env: &Env, key: DataKey) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::<String>::new(env))
+    }
+
+    fn append_transfer_id(env: &Env, key: DataKey, transfer_id: String) {
+        let mut ids = Self::get_transfer_ids(env, key.clone());
+        ids.push_back(transfer_id);
+        env.storage().persistent().set(&key, &ids);
+    }
+
+    fn paginate_transfers(
+        env: &Env,
+        transfer_ids: Vec<String>,
+        status: Option<TransferStatus>,
+        pagination: Pagination,
+    ) -> TransferPaginatedResult {
+        let (data, next_cursor) = pagination.collect(env, &transfer_ids, |transfer_id| {
+            Self::load_transfer(env, &transfer_id)
+                .filter(|transfer| status.is_none() || status.as_ref() == Some(&transfer.status))
+        });
+
+        TransferPaginatedResult {
+            data,
+            limit: pagination.limit,
+            next_cursor,
+        }
+    }
+
     // --- Multisig Functions ---
 
     pub fn init_multisig_config(
@@ -768,25 +1833,15 @@ impl CertificateContract {
         threshold: u32,
         signers: Vec<Address>,
         max_signers: u32,
+        weights: Option<Map<Address, u32>>,
         admin: Address,
     ) {
         admin.require_auth();
-        #[allow(clippy::unnecessary_cast)]
-        if threshold == 0
-            || signers.is_empty()
-            || threshold > signers.len() as u32
-            || max_signers < threshold
-        {
+        let config = MultisigConfig::new(threshold, signers, max_signers, weights);
+        if !config.is_valid() {
             panic!("Invalid multisig parameters");
         }
-        env.storage().persistent().set(
-            &DataKey::MultisigConfig(issuer.clone()),
-            &MultisigConfig {
-                threshold,
-                signers,
-                max_signers,
-            },
-        );
+        Self::store_multisig_config(&env, &issuer, &config);
         env.storage()
             .persistent()
             .set(&DataKey::IssuerAdmin(issuer), &admin);
@@ -798,6 +1853,7 @@ impl CertificateContract {
         new_threshold: Option<u32>,
         new_signers: Option<Vec<Address>>,
         new_max_signers: Option<u32>,
+        new_weights: Option<Map<Address, u32>>,
     ) {
         let admin: Address = env
             .storage()
@@ -805,15 +1861,16 @@ impl CertificateContract {
             .get(&DataKey::IssuerAdmin(issuer.clone()))
             .expect("Issuer admin not found");
         admin.require_auth();
+        let routes = Self::open_request_routes(&env, &issuer);
 
-        let mut config: MultisigConfig = env
-            .storage()
-            .persistent()
-            .get(&DataKey::MultisigConfig(issuer.clone()))
-            .expect("Multisig config not found");
+        let mut config =
+            Self::load_multisig_config(&env, &issuer).expect("Multisig config not found");
 
         if let Some(signers) = new_signers {
-            config.signers = signers;
+            config.set_signers(signers);
+        }
+        if let Some(weights) = new_weights {
+            config.set_weights(weights);
         }
         if let Some(threshold) = new_threshold {
             config.threshold = threshold;
@@ -822,32 +1879,231 @@ impl CertificateContract {
             config.max_signers = max_signers;
         }
 
-        #[allow(clippy::unnecessary_cast)]
-        if config.threshold == 0
-            || config.signers.is_empty()
-            || config.threshold > config.signers.len() as u32
-            || config.max_signers < config.threshold
-        {
+        if !config.is_valid() {
             panic!("Invalid updated multisig parameters");
         }
 
+        Self::store_multisig_config(&env, &issuer, &config);
+        Self::bump_config_version(&env, &issuer);
+        Self::reroute_open_requests(&env, &routes);
+    }
+
+    /// Rewrite a pre-weights multisig configuration with weight 1 per signer
+    pub fn migrate_multisig_config(env: Env, issuer: Address) -> MultisigConfig {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+
+        let config = Self::load_multisig_config(&env, &issuer).expect("Multisig config not found");
+        Self::store_multisig_config(&env, &issuer, &config);
+        config
+    }
+
+    /// Set a named multisig policy used for proposals of the given certificate type
+    pub fn set_multisig_policy(
+        env: Env,
+        issuer: Address,
+        certificate_type: String,
+        threshold: u32,
+        signers: Vec<Address>,
+        max_signers: u32,
+        weights: Option<Map<Address, u32>>,
+    ) {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+        let routes = Self::open_request_routes(&env, &issuer);
+
+        let config = MultisigConfig::new(threshold, signers, max_signers, weights);
+        if !config.is_valid() {
+            panic!("Invalid multisig parameters");
+        }
+
+        env.storage().persistent().set(
+            &DataKey::MultisigPolicy(issuer.clone(), certificate_type.clone()),
+            &config,
+        );
+        Self::bump_config_version(&env, &issuer);
+        Self::reroute_open_requests(&env, &routes);
+
+        let mut types = Self::get_multisig_policy_types(env.clone(), issuer.clone());
+        if !types.contains(&certificate_type) {
+            types.push_back(certificate_type);
+            env.storage()
+                .persistent()
+                .set(&DataKey::MultisigPolicyTypes(issuer), &types);
+        }
+    }
+
+    /// Remove a named multisig policy; the certificate type falls back to the default config
+    pub fn remove_multisig_policy(env: Env, issuer: Address, certificate_type: String) {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+        let routes = Self::open_request_routes(&env, &issuer);
+
+        env.storage().persistent().remove(&DataKey::MultisigPolicy(
+            issuer.clone(),
+            certificate_type.clone(),
+        ));
+        Self::bump_config_version(&env, &issuer);
+        Self::reroute_open_requests(&env, &routes);
+
+        let types = Self::get_multisig_policy_types(env.clone(), issuer.clone());
+        let mut remaining = Vec::<String>::new(&env);
+        for existing in types.iter() {
+            if existing != certificate_type {
+                remaining.push_back(existing);
+            }
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::MultisigPolicyTypes(issuer), &remaining);
+    }
+
+    /// Get the named multisig policy for a certificate type
+    pub fn get_multisig_policy(
+        env: Env,
+        issuer: Address,
+        certificate_type: String,
+    ) -> MultisigConfig {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MultisigPolicy(issuer, certificate_type))
+            .expect("Multisig policy not found")
+    }
+
+    /// Get the certificate types that have a named multisig policy
+    pub fn get_multisig_policy_types(env: Env, issuer: Address) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MultisigPolicyTypes(issuer))
+            .unwrap_or(Vec::<String>::new(&env))
+    }
+
+    /// Choose how in-flight requests are evaluated after signer or threshold changes
+    pub fn set_signer_rotation_policy(env: Env, issuer: Address, policy: SignerRotationPolicy) {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+        let routes = Self::open_request_routes(&env, &issuer);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::SignerRotationPolicy(issuer.clone()), &policy);
+        Self::reroute_open_requests(&env, &routes);
+    }
+
+    /// Get the signer rotation policy for an issuer (re-evaluation by default)
+    pub fn get_signer_rotation_policy(env: Env, issuer: Address) -> SignerRotationPolicy {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SignerRotationPolicy(issuer))
+            .unwrap_or(SignerRotationPolicy::ReevaluateCurrentRules)
+    }
+
+    /// Get the version of an issuer's multisig rules, bumped on every config or policy change
+    pub fn get_multisig_config_version(env: Env, issuer: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MultisigConfigVersion(issuer))
+            .unwrap_or(0)
+    }
+
+    /// Set the addresses (besides the issuer and its signers) allowed to propose certificates
+    pub fn set_proposers(env: Env, issuer: Address, proposers: Vec<Address>) {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposers(issuer), &proposers);
+    }
+
+    /// Get the configured proposers for an issuer
+    pub fn get_proposers(env: Env, issuer: Address) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Proposers(issuer))
+            .unwrap_or(Vec::<Address>::new(&env))
+    }
+
+    /// Set how many open requests each proposer may hold for an issuer
+    pub fn set_proposer_quota(env: Env, issuer: Address, quota: u32) {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+
+        if quota == 0 {
+            panic!("Proposer quota must be greater than zero");
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProposerQuota(issuer), &quota);
+    }
+
+    /// Get the open-request quota per proposer for an issuer
+    pub fn get_proposer_quota(env: Env, issuer: Address) -> u32 {
         env.storage()
             .persistent()
-            .set(&DataKey::MultisigConfig(issuer), &config);
+            .get(&DataKey::ProposerQuota(issuer))
+            .unwrap_or(DEFAULT_PROPOSER_QUOTA)
     }
 
+    /// Get the number of open requests a proposer holds for an issuer
+    pub fn get_open_request_count(env: Env, issuer: Address, proposer: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OpenRequestCount(issuer, proposer))
+            .unwrap_or(0)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn propose_certificate(
         env: Env,
         request_id: String,
         issuer: Address,
+        proposer: Address,
         recipient: Address,
         metadata: String,
-        expiration_days: u32,
+        request_window: Deadline,
+        certificate_type: String,
+        validity: CertificateValidity,
+        transfer_policy: TransferPolicy,
+        metadata_schema: Option<String>,
     ) -> PendingRequest {
-        let config: MultisigConfig = env
-            .storage()
-            .persistent()
-            .get(&DataKey::MultisigConfig(issuer.clone()))
+        proposer.require_auth();
+
+        match validity {
+            CertificateValidity::ExpiresAt(timestamp) if timestamp <= env.ledger().timestamp() => {
+                panic!("Certificate expiry must be in the future")
+            }
+            CertificateValidity::Duration(0) => panic!("Certificate validity must be non-zero"),
+            _ => {}
+        }
+
+        Self::require_known_type(&env, &issuer, &certificate_type);
+        let config = Self::load_policy(&env, &issuer, &certificate_type)
             .expect("Issuer does not have multisig configuration");
         if env
             .storage()
@@ -857,42 +2113,96 @@ impl CertificateContract {
             panic!("Request already exists");
         }
 
+        // Only the issuer, its signers or its configured proposers may propose
+        if proposer != issuer
+            && !config.signers.contains(&proposer)
+            && !Self::get_proposers(env.clone(), issuer.clone()).contains(&proposer)
+        {
+            panic!("Proposer is not authorized for this issuer");
+        }
+
+        // Cap the number of open requests each proposer can hold
+        let open_key = DataKey::OpenRequestCount(issuer.clone(), proposer.clone());
+        let open_requests: u32 = env.storage().persistent().get(&open_key).unwrap_or(0);
+        if open_requests >= Self::get_proposer_quota(env.clone(), issuer.clone()) {
+            panic!("Proposer has too many open requests");
+        }
+        env.storage()
+            .persistent()
+            .set(&open_key, &(open_requests + 1));
+
         let request = PendingRequest {
             id: request_id.clone(),
             issuer: issuer.clone(),
             recipient: recipient.clone(),
             metadata: metadata.clone(),
-            proposer: issuer.clone(),
+            proposer,
             approvals: Vec::new(&env),
             rejections: Vec::new(&env),
             rejection_reason: None,
             created_at: env.ledger().timestamp(),
-            expires_at: env.ledger().timestamp() + (expiration_days as u64 * 24 * 60 * 60),
+            expires_at: request_window.from_now(&env),
             status: RequestStatus::Pending,
+            certificate_type,
+            executable_at: OptionalDeadline::None,
+            amendment_count: 0,
+            config_version: Self::get_multisig_config_version(env.clone(), issuer.clone()),
+            validity,
+            transfer_policy,
+            metadata_schema,
         };
 
         env.storage()
             .persistent()
             .set(&DataKey::PendingRequest(request_id.clone()), &request);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RequestConfigSnapshot(request_id.clone()), &config);
 
-        Self::append_request_id(&env, DataKey::IssuerRequestIds(issuer), request_id.clone());
+        Self::append_request_id(
+            &env,
+            DataKey::IssuerRequestIds(issuer.clone()),
+            request_id.clone(),
+        );
+        Self::append_request_id(
+            &env,
+            DataKey::IssuerOpenRequestIds(issuer),
+            request_id.clone(),
+        );
 
         for signer in config.signers.iter() {
             Self::append_request_id(&env, DataKey::SignerRequestIds(signer), request_id.clone());
         }
 
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("proposed"),
+                request.issuer.clone(),
+            ),
+            RequestProposedEvent {
+                request_id,
+                issuer: request.issuer.clone(),
+                proposer: request.proposer.clone(),
+                recipient: request.recipient.clone(),
+                signers: config.signers.clone(),
+                threshold: config.threshold,
+                expires_at: request.expires_at.clone(),
+            },
+        );
+
         request
     }
 
     pub fn approve_request(env: Env, request_id: String, approver: Address) -> SignatureResult {
         approver.require_auth();
-        let mut request: PendingRequest = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingRequest(request_id.clone()))
-            .expect("Request not found");
+        let mut request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
 
-        if env.ledger().timestamp() > request.expires_at {
+        if request.expires_at.has_passed(&env) {
+            if request.status.is_open() {
+                Self::close_request(&env, &request);
+            }
             request.status = RequestStatus::Expired;
             env.storage()
                 .persistent()
@@ -912,11 +2222,7 @@ impl CertificateContract {
             };
         }
 
-        let config: MultisigConfig = env
-            .storage()
-            .persistent()
-            .get(&DataKey::MultisigConfig(request.issuer.clone()))
-            .expect("Config not found");
+        let config = Self::effective_config(&env, &mut request);
         if !config.signers.contains(&approver) {
             return SignatureResult {
                 success: false,
@@ -933,12 +2239,33 @@ impl CertificateContract {
             };
         }
 
-        request.approvals.push_back(approver);
+        request.approvals.push_back(approver.clone());
 
-        if request.approvals.len() >= config.threshold {
+        if config.weight_sum(&request.approvals) >= config.threshold {
             request.status = RequestStatus::Approved;
+            // Issuance stays time-locked until the issuer's execution delay has passed
+            request.executable_at = OptionalDeadline::Some(
+                Self::get_execution_delay(env.clone(), request.issuer.clone()).from_now(&env),
+            );
         }
 
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("approved"),
+                request.issuer.clone(),
+            ),
+            RequestApprovedEvent {
+                request_id: request_id.clone(),
+                approver,
+                approval_count: request.approvals.len(),
+                approval_weight: config.weight_sum(&request.approvals),
+                threshold: config.threshold,
+                status: request.status.clone(),
+                executable_at: request.executable_at.clone(),
+            },
+        );
+
         env.storage()
             .persistent()
             .set(&DataKey::PendingRequest(request_id), &request);
@@ -956,11 +2283,8 @@ impl CertificateContract {
         reason: Option<String>,
     ) -> SignatureResult {
         rejector.require_auth();
-        let mut request: PendingRequest = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingRequest(request_id.clone()))
-            .expect("Request not found");
+        let mut request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
 
         if request.status != RequestStatus::Pending {
             return SignatureResult {
@@ -970,27 +2294,51 @@ impl CertificateContract {
             };
         }
 
-        let config: MultisigConfig = env
-            .storage()
-            .persistent()
-            .get(&DataKey::MultisigConfig(request.issuer.clone()))
-            .expect("Config not found");
+        let config = Self::effective_config(&env, &mut request);
 
-        if !request.rejections.contains(&rejector) {
-            request.rejections.push_back(rejector);
-            if reason.is_some() {
-                request.rejection_reason = reason;
-            }
+        if !config.signers.contains(&rejector) {
+            return SignatureResult {
+                success: false,
+                message: String::from_str(&env, "Rejector is not an authorized signer"),
+                final_status: OptionalRequestStatus::Some(request.status),
+            };
+        }
+
+        if request.rejections.contains(&rejector) {
+            return SignatureResult {
+                success: false,
+                message: String::from_str(&env, "Request already rejected by this signer"),
+                final_status: OptionalRequestStatus::Some(request.status),
+            };
+        }
+
+        request.rejections.push_back(rejector.clone());
+        if reason.is_some() {
+            request.rejection_reason = reason;
         }
 
-        let remaining_eligible_approvers = config
-            .signers
-            .len()
-            .saturating_sub(request.rejections.len());
-        if remaining_eligible_approvers < config.threshold {
+        let remaining_achievable_weight = config
+            .total_weight()
+            .saturating_sub(config.weight_sum(&request.rejections));
+        if remaining_achievable_weight < config.threshold {
             request.status = RequestStatus::Rejected;
+            Self::close_request(&env, &request);
         }
 
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("rejected"),
+                request.issuer.clone(),
+            ),
+            RequestRejectedEvent {
+                request_id: request_id.clone(),
+                rejector,
+                reason: request.rejection_reason.clone(),
+                status: request.status.clone(),
+            },
+        );
+
         env.storage()
             .persistent()
             .set(&DataKey::PendingRequest(request_id), &request);
@@ -1001,16 +2349,114 @@ impl CertificateContract {
         }
     }
 
-    pub fn issue_approved_certificate(env: Env, request_id: String) -> bool {
-        let mut request: PendingRequest = env
+    /// Set the delay, in ledgers or seconds, between reaching the approval threshold and
+    /// issuance
+    pub fn set_execution_delay(env: Env, issuer: Address, delay: Deadline) {
+        let admin: Address = env
             .storage()
             .persistent()
-            .get(&DataKey::PendingRequest(request_id.clone()))
-            .expect("Request not found");
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::ExecutionDelay(issuer), &delay);
+    }
+
+    /// Get the execution delay for an issuer, zero ledgers if none is configured
+    pub fn get_execution_delay(env: Env, issuer: Address) -> Deadline {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ExecutionDelay(issuer))
+            .unwrap_or(Deadline::Ledger(0))
+    }
+
+    /// Veto an approved request while it is still time-locked (any signer can veto)
+    pub fn veto_request(
+        env: Env,
+        request_id: String,
+        signer: Address,
+        reason: Option<String>,
+    ) -> SignatureResult {
+        signer.require_auth();
+
+        let mut request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
+
+        if request.status != RequestStatus::Approved || request.executable_at.is_reached(&env) {
+            return SignatureResult {
+                success: false,
+                message: String::from_str(&env, "Request is not time-locked"),
+                final_status: OptionalRequestStatus::Some(request.status),
+            };
+        }
+
+        let config = Self::effective_config(&env, &mut request);
+        if !config.signers.contains(&signer) {
+            return SignatureResult {
+                success: false,
+                message: String::from_str(&env, "Signer is not an authorized signer"),
+                final_status: OptionalRequestStatus::Some(request.status),
+            };
+        }
+
+        request.status = RequestStatus::Vetoed;
+        Self::close_request(&env, &request);
+        request.rejections.push_back(signer.clone());
+        if reason.is_some() {
+            request.rejection_reason = reason;
+        }
+
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("vetoed"),
+                request.issuer.clone(),
+            ),
+            RequestVetoedEvent {
+                request_id: request_id.clone(),
+                signer,
+                reason: request.rejection_reason.clone(),
+            },
+        );
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingRequest(request_id), &request);
+
+        SignatureResult {
+            success: true,
+            message: String::from_str(&env, "Request vetoed"),
+            final_status: OptionalRequestStatus::Some(request.status),
+        }
+    }
+
+    pub fn issue_approved_certificate(env: Env, request_id: String) -> bool {
+        let mut request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
         if request.status != RequestStatus::Approved {
             return false;
         }
 
+        Self::require_not_paused(&env, &request.issuer);
+
+        // Still inside the veto window
+        if !request.executable_at.is_reached(&env) {
+            return false;
+        }
+
+        // Signer changes since approval may leave the request below the threshold
+        let config = Self::effective_config(&env, &mut request);
+        if config.weight_sum(&request.approvals) < config.threshold {
+            request.status = RequestStatus::Pending;
+            request.executable_at = OptionalDeadline::None;
+            env.storage()
+                .persistent()
+                .set(&DataKey::PendingRequest(request_id), &request);
+            return false;
+        }
+
         // Issue the actual certificate
         Self::issue_certificate(
             env.clone(),
@@ -1018,10 +2464,26 @@ impl CertificateContract {
             request.issuer.clone(),
             request.recipient.clone(),
             request.metadata.clone(),
-            Some(request.expires_at),
+            request.validity.expires_at(env.ledger().timestamp()),
+            request.transfer_policy.clone(),
+            request.metadata_schema.clone(),
+            request.certificate_type.clone(),
         );
 
         request.status = RequestStatus::Issued;
+        Self::close_request(&env, &request);
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("issued"),
+                request.issuer.clone(),
+            ),
+            RequestIssuedEvent {
+                request_id: request_id.clone(),
+                issuer: request.issuer.clone(),
+                recipient: request.recipient.clone(),
+            },
+        );
         env.storage()
             .persistent()
             .set(&DataKey::PendingRequest(request_id), &request);
@@ -1039,19 +2501,13 @@ impl CertificateContract {
         if !caller_is_admin {
             issuer.require_auth();
         }
-        env.storage()
-            .persistent()
-            .get(&DataKey::MultisigConfig(issuer))
-            .expect("Multisig.config not found")
+        Self::load_multisig_config(&env, &issuer).expect("Multisig.config not found")
     }
 
     pub fn get_pending_request(env: Env, request_id: String, caller: Address) -> PendingRequest {
         caller.require_auth();
-        let request: PendingRequest = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingRequest(request_id))
-            .expect("Request not found");
+        let request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
         // Only the issuer, proposer, or an authorized signer may read the request
         let admin: Address = env
             .storage()
@@ -1061,10 +2517,7 @@ impl CertificateContract {
         let is_authorized = caller == request.issuer
             || caller == request.proposer
             || caller == admin
-            || env
-                .storage()
-                .persistent()
-                .get::<_, MultisigConfig>(&DataKey::MultisigConfig(request.issuer.clone()))
+            || Self::load_policy(&env, &request.issuer, &request.certificate_type)
                 .map(|c| c.signers.contains(&caller))
                 .unwrap_or(false);
         if !is_authorized {
@@ -1074,49 +2527,147 @@ impl CertificateContract {
     }
 
     pub fn is_expired(env: Env, request_id: String) -> bool {
-        let request: PendingRequest = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingRequest(request_id))
-            .expect("Request not found");
-        env.ledger().timestamp() > request.expires_at
+        let request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
+        request.expires_at.has_passed(&env)
     }
 
+    /// Get the requests proposed for an issuer, optionally filtered by status (cursor-paginated)
     pub fn get_pending_requests_for_issuer(
         env: Env,
         issuer: Address,
+        status: Option<RequestStatus>,
         pagination: Pagination,
     ) -> PaginatedResult {
         Self::paginate_requests(
             &env,
             Self::get_request_ids(&env, DataKey::IssuerRequestIds(issuer)),
+            status,
             pagination,
         )
     }
 
+    /// Get the requests routed to a signer (open requests only), optionally filtered by status (cursor-paginated)
     pub fn get_pending_requests_for_signer(
         env: Env,
         signer: Address,
+        status: Option<RequestStatus>,
         pagination: Pagination,
     ) -> PaginatedResult {
         Self::paginate_requests(
             &env,
             Self::get_request_ids(&env, DataKey::SignerRequestIds(signer)),
+            status,
             pagination,
         )
     }
 
-    pub fn cancel_request(env: Env, request_id: String, requester: Address) -> bool {
-        requester.require_auth();
-        let mut request: PendingRequest = env
+    /// Correct the metadata or recipient of an open request (only proposer can amend).
+    /// Collected approvals and rejections are cleared so signers re-review the new content.
+    pub fn amend_request(
+        env: Env,
+        request_id: String,
+        proposer: Address,
+        new_metadata: String,
+        new_recipient: Address,
+    ) -> PendingRequest {
+        proposer.require_auth();
+
+        let mut request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
+
+        if request.proposer != proposer {
+            panic!("Only proposer can amend the request");
+        }
+
+        if !request.status.is_open() {
+            panic!("Only open requests can be amended");
+        }
+
+        if request.expires_at.has_passed(&env) {
+            panic!("Request has expired");
+        }
+
+        let history_key = DataKey::RequestAmendments(request_id.clone());
+        let mut history: Vec<RequestAmendment> = env
             .storage()
             .persistent()
-            .get(&DataKey::PendingRequest(request_id.clone()))
-            .expect("Request not found");
+            .get(&history_key)
+            .unwrap_or(Vec::new(&env));
+
+        request.amendment_count += 1;
+        history.push_back(RequestAmendment {
+            amendment: request.amendment_count,
+            previous_metadata: request.metadata.clone(),
+            previous_recipient: request.recipient.clone(),
+            amended_by: proposer,
+            amended_at: env.ledger().timestamp(),
+        });
+
+        request.metadata = new_metadata;
+        request.recipient = new_recipient;
+        request.approvals = Vec::new(&env);
+        request.rejections = Vec::new(&env);
+        request.rejection_reason = None;
+        request.executable_at = OptionalDeadline::None;
+        request.status = RequestStatus::Pending;
+
+        env.storage().persistent().set(&history_key, &history);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingRequest(request_id.clone()), &request);
+
+        let signers = Self::load_policy(&env, &request.issuer, &request.certificate_type)
+            .map(|config| config.signers)
+            .unwrap_or(Vec::new(&env));
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("amended"),
+                request.issuer.clone(),
+            ),
+            RequestAmendedEvent {
+                request_id,
+                issuer: request.issuer.clone(),
+                amendment: request.amendment_count,
+                signers,
+            },
+        );
+
+        request
+    }
+
+    /// Get the amendment history of a request, oldest first
+    pub fn get_request_amendments(env: Env, request_id: String) -> Vec<RequestAmendment> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RequestAmendments(request_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn cancel_request(env: Env, request_id: String, requester: Address) -> bool {
+        requester.require_auth();
+        let mut request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
         if request.proposer != requester {
             panic!("Only proposer can cancel");
         }
-        request.status = RequestStatus::Rejected;
+        if request.status != RequestStatus::Pending {
+            return false;
+        }
+        request.status = RequestStatus::Cancelled;
+        Self::close_request(&env, &request);
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("cancelled"),
+                request.issuer.clone(),
+            ),
+            RequestCancelledEvent {
+                request_id: request_id.clone(),
+                proposer: requester,
+            },
+        );
         env.storage()
             .persistent()
             .set(&DataKey::PendingRequest(request_id), &request);
@@ -1166,11 +2717,7 @@ impl CertificateContract {
         let mut failed: u32 = 0;
 
         for id in ids.iter() {
-            if let Some(cert) = env
-                .storage()
-                .persistent()
-                .get::<_, Certificate>(&DataKey::Certificate(id.clone()))
-            {
+            if let Some(cert) = Self::load_certificate(&env, &id) {
                 let is_expired_by_time = cert
                     .expires_at
                     .map_or(false, |exp| env.ledger().timestamp() >= exp);
@@ -1178,7 +2725,8 @@ impl CertificateContract {
                 let is_revoked = cert.status == CertificateStatus::Revoked
                     || cert.status == CertificateStatus::Suspended
                     || cert.status == CertificateStatus::Expired
-                    || is_expired_by_time;
+                    || is_expired_by_time
+                    || !Self::issuer_backs(&env, &cert);
 
                 if !is_revoked {
                     successful += 1;
@@ -1225,11 +2773,8 @@ impl CertificateContract {
             panic!("Only admin can set certificate expiry");
         }
 
-        let mut cert: Certificate = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Certificate(id.clone()))
-            .expect("Certificate not found");
+        let mut cert: Certificate =
+            Self::load_certificate(&env, &id).expect("Certificate not found");
 
         cert.expires_at = Some(expiry_time);
         env.storage()
@@ -1239,21 +2784,18 @@ impl CertificateContract {
 
     /// Get certificate expiry time
     pub fn get_certificate_expiry(env: Env, id: String) -> Option<u64> {
-        if let Some(cert) = env
-            .storage()
-            .persistent()
-            .get::<_, Certificate>(&DataKey::Certificate(id))
-        {
+        if let Some(cert) = Self::load_certificate(&env, &id) {
             cert.expires_at
         } else {
             None
         }
     }
 
-    /// Get all certificates issued by a given issuer (paginated)
+    /// Get certificates issued by a given issuer, optionally filtered by status (cursor-paginated)
     pub fn get_certificates_by_issuer(
         env: Env,
         issuer: Address,
+        status: Option<CertificateStatus>,
         pagination: Pagination,
     ) -> CertPaginatedResult {
         let ids: Vec<String> = env
@@ -1261,13 +2803,14 @@ impl CertificateContract {
             .persistent()
             .get(&DataKey::IssuerCertIds(issuer))
             .unwrap_or(Vec::<String>::new(&env));
-        Self::paginate_certificates(&env, ids, pagination)
+        Self::paginate_certificates(&env, ids, status, pagination)
     }
 
-    /// Get all certificates owned by a given address (paginated)
+    /// Get certificates owned by a given address, optionally filtered by status (cursor-paginated)
     pub fn get_certificates_by_owner(
         env: Env,
         owner: Address,
+        status: Option<CertificateStatus>,
         pagination: Pagination,
     ) -> CertPaginatedResult {
         let ids: Vec<String> = env
@@ -1275,49 +2818,24 @@ impl CertificateContract {
             .persistent()
             .get(&DataKey::OwnerCertIds(owner))
             .unwrap_or(Vec::<String>::new(&env));
-        Self::paginate_certificates(&env, ids, pagination)
+        Self::paginate_certificates(&env, ids, status, pagination)
     }
 
     fn paginate_certificates(
         env: &Env,
         cert_ids: Vec<String>,
+        status: Option<CertificateStatus>,
         pagination: Pagination,
     ) -> CertPaginatedResult {
-        let total = cert_ids.len();
-        let mut page_data = Vec::<Certificate>::new(env);
-
-        if pagination.limit == 0 {
-            return CertPaginatedResult {
-                data: page_data,
-                total,
-                page: pagination.page,
-                limit: pagination.limit,
-                has_next: false,
-            };
-        }
-
-        let start = pagination.page.saturating_mul(pagination.limit);
-        let end = total.min(start.saturating_add(pagination.limit));
-        let mut index = start;
-        while index < end {
-            if let Some(id) = cert_ids.get(index) {
-                if let Some(cert) = env
-                    .storage()
-                    .persistent()
-                    .get::<_, Certificate>(&DataKey::Certificate(id))
-                {
-                    page_data.push_back(cert);
-                }
-            }
-            index += 1;
-        }
+        let (data, next_cursor) = pagination.collect(env, &cert_ids, |id| {
+            Self::load_certificate(env, &id)
+                .filter(|cert| status.is_none() || status.as_ref() == Some(&cert.status))
+        });
 
         CertPaginatedResult {
-            data: page_data,
-            total,
-            page: pagination.page,
+            data,
             limit: pagination.limit,
-            has_next: end < total,
+            next_cursor,
         }
     }
 
@@ -1333,6 +2851,170 @@ impl CertificateContract {
         }
     }
 
+    /// Reject a certificate type without a policy once the issuer has named policies
+    fn require_known_type(env: &Env, issuer: &Address, certificate_type: &String) {
+        let named: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MultisigPolicyTypes(issuer.clone()))
+            .unwrap_or(Vec::new(env));
+        if !named.is_empty() && !named.contains(certificate_type) {
+            panic!("Unknown certificate type");
+        }
+    }
+
+    /// Resolve the policy for a certificate type, falling back to the issuer's default config
+    fn load_policy(
+        env: &Env,
+        issuer: &Address,
+        certificate_type: &String,
+    ) -> Option<MultisigConfig> {
+        if let Some(policy) = env.storage().persistent().get(&DataKey::MultisigPolicy(
+            issuer.clone(),
+            certificate_type.clone(),
+        )) {
+            return Some(policy);
+        }
+        Self::load_multisig_config(env, issuer)
+    }
+
+    fn load_multisig_config(env: &Env, issuer: &Address) -> Option<MultisigConfig> {
+        let storage = env.storage().persistent();
+        if let Some(config) = storage.get(&DataKey::WeightedMultisigConfig(issuer.clone())) {
+            return Some(config);
+        }
+        storage
+            .get::<_, LegacyMultisigConfig>(&DataKey::MultisigConfig(issuer.clone()))
+            .map(MultisigConfig::from)
+    }
+
+    fn store_multisig_config(env: &Env, issuer: &Address, config: &MultisigConfig) {
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::WeightedMultisigConfig(issuer.clone()), config);
+        storage.remove(&DataKey::MultisigConfig(issuer.clone()));
+    }
+
+    fn bump_config_version(env: &Env, issuer: &Address) {
+        let version = Self::get_multisig_config_version(env.clone(), issuer.clone());
+        env.storage().persistent().set(
+            &DataKey::MultisigConfigVersion(issuer.clone()),
+            &(version + 1),
+        );
+    }
+
+    /// Resolve the rules a request is evaluated under according to the issuer's rotation
+    /// policy, dropping signatures from removed signers when the current rules apply
+    fn effective_config(env: &Env, request: &mut PendingRequest) -> MultisigConfig {
+        if Self::get_signer_rotation_policy(env.clone(), request.issuer.clone())
+            == SignerRotationPolicy::KeepOriginalRules
+        {
+            if let Some(config) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::RequestConfigSnapshot(request.id.clone()))
+            {
+                return config;
+            }
+        }
+
+        let config = Self::load_policy(env, &request.issuer, &request.certificate_type)
+            .expect("Multisig config not found");
+        let current_version =
+            Self::get_multisig_config_version(env.clone(), request.issuer.clone());
+        if request.config_version != current_version {
+            request.approvals = Self::retain_signers(env, &config, &request.approvals);
+            request.rejections = Self::retain_signers(env, &config, &request.rejections);
+            request.config_version = current_version;
+        }
+        config
+    }
+
+    fn retain_signers(
+        env: &Env,
+        config: &MultisigConfig,
+        addresses: &Vec<Address>,
+    ) -> Vec<Address> {
+        let mut retained = Vec::new(env);
+        for address in addresses.iter() {
+            if config.signers.contains(&address) {
+                retained.push_back(address);
+            }
+        }
+        retained
+    }
+
+    /// Bookkeeping once a request leaves the open states: free the proposer's quota slot,
+    /// drop the request from the inboxes of the signers it was routed to and discard its
+    /// config snapshot
+    fn close_request(env: &Env, request: &PendingRequest) {
+        let key = DataKey::OpenRequestCount(request.issuer.clone(), request.proposer.clone());
+        let open_requests: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&key, &open_requests.saturating_sub(1));
+
+        Self::remove_request_id(
+            env,
+            DataKey::IssuerOpenRequestIds(request.issuer.clone()),
+            &request.id,
+        );
+        for signer in Self::request_signers(env, request).iter() {
+            Self::remove_request_id(env, DataKey::SignerRequestIds(signer), &request.id);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RequestConfigSnapshot(request.id.clone()));
+    }
+
+    /// Signers a request is routed to: its snapshot under `KeepOriginalRules`, otherwise
+    /// the issuer's current policy for the request's certificate type
+    fn request_signers(env: &Env, request: &PendingRequest) -> Vec<Address> {
+        if Self::get_signer_rotation_policy(env.clone(), request.issuer.clone())
+            == SignerRotationPolicy::KeepOriginalRules
+        {
+            if let Some(config) = env
+                .storage()
+                .persistent()
+                .get::<_, MultisigConfig>(&DataKey::RequestConfigSnapshot(request.id.clone()))
+            {
+                return config.signers;
+            }
+        }
+        Self::load_policy(env, &request.issuer, &request.certificate_type)
+            .map(|config| config.signers)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Signers each open request of the issuer is currently routed to
+    fn open_request_routes(env: &Env, issuer: &Address) -> Map<String, Vec<Address>> {
+        let mut routes = Map::new(env);
+        for request_id in
+            Self::get_request_ids(env, DataKey::IssuerOpenRequestIds(issuer.clone())).iter()
+        {
+            if let Some(request) = Self::load_request(env, &request_id) {
+                routes.set(request_id, Self::request_signers(env, &request));
+            }
+        }
+        routes
+    }
+
+    /// Move open requests between signer inboxes after the rules that route them change
+    fn reroute_open_requests(env: &Env, routes: &Map<String, Vec<Address>>) {
+        for (request_id, previous) in routes.iter() {
+            let request: PendingRequest =
+                Self::load_request(env, &request_id).expect("Request not found");
+            let current = Self::request_signers(env, &request);
+            for signer in previous.iter() {
+                if !current.contains(&signer) {
+                    Self::remove_request_id(env, DataKey::SignerRequestIds(signer), &request_id);
+                }
+            }
+            for signer in current.iter() {
+                Self::append_request_id(env, DataKey::SignerRequestIds(signer), request_id.clone());
+            }
+        }
+    }
+
     fn append_request_id(env: &Env, key: DataKey, request_id: String) {
         let mut request_ids = Self::get_request_ids(env, key.clone());
 
@@ -1342,6 +3024,15 @@ impl CertificateContract {
         }
     }
 
+    fn remove_request_id(env: &Env, key: DataKey, request_id: &String) {
+        let mut request_ids = Self::get_request_ids(env, key.clone());
+
+        if let Some(index) = request_ids.first_index_of(request_id) {
+            request_ids.remove(index);
+            env.storage().persistent().set(&key, &request_ids);
+        }
+    }
+
     fn get_request_ids(env: &Env, key: DataKey) -> Vec<String> {
         env.storage()
             .persistent()
@@ -1352,51 +3043,19 @@ impl CertificateContract {
     fn paginate_requests(
         env: &Env,
         request_ids: Vec<String>,
+        status: Option<RequestStatus>,
         pagination: Pagination,
     ) -> PaginatedResult {
-        let mut all_requests = Vec::<PendingRequest>::new(env);
-
-        for request_id in request_ids.iter() {
-            if let Some(request) = env
-                .storage()
-                .persistent()
-                .get::<_, PendingRequest>(&DataKey::PendingRequest(request_id))
-            {
-                all_requests.push_back(request);
-            }
-        }
-
-        let total = all_requests.len();
-        let mut page_data = Vec::<PendingRequest>::new(env);
-
-        if pagination.limit == 0 {
-            return PaginatedResult {
-                data: page_data,
-                total,
-                page: pagination.page,
-                limit: pagination.limit,
-                has_next: false,
-            };
-        }
-
-        // Page is 1-indexed. Calculate start index (0-indexed)
-        let start = pagination.page.saturating_sub(1).saturating_mul(pagination.limit);
-        let end = total.min(start.saturating_add(pagination.limit));
-
-        let mut index = start;
-        while index < end {
-            if let Some(request) = all_requests.get(index) {
-                page_data.push_back(request);
-            }
-            index += 1;
-        }
+        let (data, next_cursor) = pagination.collect(env, &request_ids, |request_id| {
+            Self::load_request(env, &request_id)
+                .map(|request| request.with_lapsed_expiry(env))
+                .filter(|request| status.is_none() || status.as_ref() == Some(&request.status))
+        });
 
         PaginatedResult {
-            data: page_data,
-            total,
-            page: pagination.page,
+            data,
             limit: pagination.limit,
-            has_next: end < total,
+            next_cursor,
         }
     }
 }
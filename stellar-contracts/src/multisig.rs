@@ -1,8 +1,13 @@
-use soroban_sdk::{contract, contractimpl, Address, Env, IntoVal, String, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, Env, IntoVal, Map, String, Symbol, Val, Vec,
+};
 
 use crate::{
-    DataKey, MultisigConfig, OptionalRequestStatus, PaginatedResult, Pagination, PendingRequest,
-    RequestStatus, SignatureResult,
+    CertificateValidity, DataKey, Deadline, LegacyMultisigConfig, MultisigConfig, OptionalDeadline,
+    OptionalRequestStatus, PaginatedResult, Pagination, PendingRequest, RequestAmendedEvent,
+    RequestAmendment, RequestApprovedEvent, RequestCancelledEvent, RequestIssuedEvent,
+    RequestProposedEvent, RequestRejectedEvent, RequestStatus, RequestVetoedEvent, SignatureResult,
+    SignerRotationPolicy, TransferPolicy, DEFAULT_PROPOSER_QUOTA,
 };
 
 #[contract]
@@ -17,38 +22,24 @@ impl MultisigCertificateContract {
         threshold: u32,
         signers: Vec<Address>,
         max_signers: u32,
+        weights: Option<Map<Address, u32>>,
         admin: Address,
     ) {
         admin.require_auth();
 
         // Validate parameters
-        #[allow(clippy::unnecessary_cast)]
-        if threshold == 0
-            || signers.is_empty()
-            || threshold > signers.len() as u32
-            || max_signers < threshold
-        {
+        let config = MultisigConfig::new(threshold, signers, max_signers, weights);
+        if !config.is_valid() {
             panic!("Invalid multisig parameters");
         }
 
         // Check if already initialized
-        if env
-            .storage()
-            .instance()
-            .has(&DataKey::MultisigConfig(issuer.clone()))
-        {
+        if Self::load_config(&env, &issuer).is_some() {
             panic!("Multisig config already exists for this issuer");
         }
 
         // Store configuration
-        env.storage().instance().set(
-            &DataKey::MultisigConfig(issuer.clone()),
-            &MultisigConfig {
-                threshold,
-                signers,
-                max_signers,
-            },
-        );
+        Self::store_config(&env, &issuer, &config);
 
         // Store admin for this issuer
         env.storage()
@@ -63,6 +54,7 @@ impl MultisigCertificateContract {
         new_threshold: Option<u32>,
         new_signers: Option<Vec<Address>>,
         new_max_signers: Option<u32>,
+        new_weights: Option<Map<Address, u32>>,
     ) {
         let admin: Address = env
             .storage()
@@ -70,16 +62,16 @@ impl MultisigCertificateContract {
             .get(&DataKey::IssuerAdmin(issuer.clone()))
             .expect("Issuer admin not found");
         admin.require_auth();
+        let routes = Self::open_request_routes(&env, &issuer);
 
-        let mut config: MultisigConfig = env
-            .storage()
-            .instance()
-            .get(&DataKey::MultisigConfig(issuer.clone()))
-            .expect("Multisig config not found");
+        let mut config = Self::load_config(&env, &issuer).expect("Multisig config not found");
 
         // Update configuration
         if let Some(signers) = new_signers {
-            config.signers = signers;
+            config.set_signers(signers);
+        }
+        if let Some(weights) = new_weights {
+            config.set_weights(weights);
         }
         if let Some(threshold) = new_threshold {
             config.threshold = threshold;
@@ -89,41 +81,237 @@ impl MultisigCertificateContract {
         }
 
         // Validate updated configuration
-        #[allow(clippy::unnecessary_cast)]
-        if config.threshold == 0
-            || config.signers.is_empty()
-            || config.threshold > config.signers.len() as u32
-            || config.max_signers < config.threshold
-        {
+        if !config.is_valid() {
             panic!("Invalid updated multisig parameters");
         }
 
-        env.storage()
+        Self::store_config(&env, &issuer, &config);
+        Self::bump_config_version(&env, &issuer);
+        Self::reroute_open_requests(&env, &routes);
+    }
+
+    /// Rewrite a pre-weights multisig configuration with weight 1 per signer
+    pub fn migrate_multisig_config(env: Env, issuer: Address) -> MultisigConfig {
+        let admin: Address = env
+            .storage()
             .instance()
-            .set(&DataKey::MultisigConfig(issuer), &config);
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+
+        let config = Self::load_config(&env, &issuer).expect("Multisig config not found");
+        Self::store_config(&env, &issuer, &config);
+        config
     }
 
     /// Get multisig configuration for an issuer
     pub fn get_multisig_config(env: Env, issuer: Address) -> MultisigConfig {
+        Self::load_config(&env, &issuer).expect("Multisig config not found")
+    }
+
+    /// Set a named multisig policy used for proposals of the given certificate type
+    pub fn set_multisig_policy(
+        env: Env,
+        issuer: Address,
+        certificate_type: String,
+        threshold: u32,
+        signers: Vec<Address>,
+        max_signers: u32,
+        weights: Option<Map<Address, u32>>,
+    ) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+        let routes = Self::open_request_routes(&env, &issuer);
+
+        let config = MultisigConfig::new(threshold, signers, max_signers, weights);
+        if !config.is_valid() {
+            panic!("Invalid multisig parameters");
+        }
+
+        env.storage().instance().set(
+            &DataKey::MultisigPolicy(issuer.clone(), certificate_type.clone()),
+            &config,
+        );
+        Self::bump_config_version(&env, &issuer);
+        Self::reroute_open_requests(&env, &routes);
+
+        let mut types = Self::get_multisig_policy_types(env.clone(), issuer.clone());
+        if !types.contains(&certificate_type) {
+            types.push_back(certificate_type);
+            env.storage()
+                .instance()
+                .set(&DataKey::MultisigPolicyTypes(issuer), &types);
+        }
+    }
+
+    /// Remove a named multisig policy; the certificate type falls back to the default config
+    pub fn remove_multisig_policy(env: Env, issuer: Address, certificate_type: String) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+        let routes = Self::open_request_routes(&env, &issuer);
+
+        env.storage().instance().remove(&DataKey::MultisigPolicy(
+            issuer.clone(),
+            certificate_type.clone(),
+        ));
+        Self::bump_config_version(&env, &issuer);
+        Self::reroute_open_requests(&env, &routes);
+
+        let types = Self::get_multisig_policy_types(env.clone(), issuer.clone());
+        let mut remaining = Vec::<String>::new(&env);
+        for existing in types.iter() {
+            if existing != certificate_type {
+                remaining.push_back(existing);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MultisigPolicyTypes(issuer), &remaining);
+    }
+
+    /// Get the named multisig policy for a certificate type
+    pub fn get_multisig_policy(
+        env: Env,
+        issuer: Address,
+        certificate_type: String,
+    ) -> MultisigConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::MultisigPolicy(issuer, certificate_type))
+            .expect("Multisig policy not found")
+    }
+
+    /// Get the certificate types that have a named multisig policy
+    pub fn get_multisig_policy_types(env: Env, issuer: Address) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&DataKey::MultisigPolicyTypes(issuer))
+            .unwrap_or(Vec::<String>::new(&env))
+    }
+
+    /// Choose how in-flight requests are evaluated after signer or threshold changes
+    pub fn set_signer_rotation_policy(env: Env, issuer: Address, policy: SignerRotationPolicy) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+        let routes = Self::open_request_routes(&env, &issuer);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::SignerRotationPolicy(issuer.clone()), &policy);
+        Self::reroute_open_requests(&env, &routes);
+    }
+
+    /// Get the signer rotation policy for an issuer (re-evaluation by default)
+    pub fn get_signer_rotation_policy(env: Env, issuer: Address) -> SignerRotationPolicy {
+        env.storage()
+            .instance()
+            .get(&DataKey::SignerRotationPolicy(issuer))
+            .unwrap_or(SignerRotationPolicy::ReevaluateCurrentRules)
+    }
+
+    /// Get the version of an issuer's multisig rules, bumped on every config or policy change
+    pub fn get_multisig_config_version(env: Env, issuer: Address) -> u32 {
         env.storage()
             .instance()
-            .get(&DataKey::MultisigConfig(issuer))
-            .expect("Multisig config not found")
+            .get(&DataKey::MultisigConfigVersion(issuer))
+            .unwrap_or(0)
+    }
+
+    /// Set the addresses (besides the issuer and its signers) allowed to propose certificates
+    pub fn set_proposers(env: Env, issuer: Address, proposers: Vec<Address>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Proposers(issuer), &proposers);
+    }
+
+    /// Get the configured proposers for an issuer
+    pub fn get_proposers(env: Env, issuer: Address) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Proposers(issuer))
+            .unwrap_or(Vec::<Address>::new(&env))
+    }
+
+    /// Set how many open requests each proposer may hold for an issuer
+    pub fn set_proposer_quota(env: Env, issuer: Address, quota: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+
+        if quota == 0 {
+            panic!("Proposer quota must be greater than zero");
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ProposerQuota(issuer), &quota);
+    }
+
+    /// Get the open-request quota per proposer for an issuer
+    pub fn get_proposer_quota(env: Env, issuer: Address) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProposerQuota(issuer))
+            .unwrap_or(DEFAULT_PROPOSER_QUOTA)
+    }
+
+    /// Get the number of open requests a proposer holds for an issuer
+    pub fn get_open_request_count(env: Env, issuer: Address, proposer: Address) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::OpenRequestCount(issuer, proposer))
+            .unwrap_or(0)
     }
 
     /// Propose a certificate for multisig approval
+    #[allow(clippy::too_many_arguments)]
     pub fn propose_certificate(
         env: Env,
         request_id: String,
         issuer: Address,
+        proposer: Address,
         recipient: Address,
         metadata: String,
-        expiration_days: u32,
+        request_window: Deadline,
+        certificate_type: String,
+        validity: CertificateValidity,
+        transfer_policy: TransferPolicy,
+        metadata_schema: Option<String>,
     ) -> PendingRequest {
-        let config: MultisigConfig = env
-            .storage()
-            .instance()
-            .get(&DataKey::MultisigConfig(issuer.clone()))
+        proposer.require_auth();
+
+        match validity {
+            CertificateValidity::ExpiresAt(timestamp) if timestamp <= env.ledger().timestamp() => {
+                panic!("Certificate expiry must be in the future")
+            }
+            CertificateValidity::Duration(0) => panic!("Certificate validity must be non-zero"),
+            _ => {}
+        }
+
+        Self::require_known_type(&env, &issuer, &certificate_type);
+        let config = Self::load_policy(&env, &issuer, &certificate_type)
             .expect("Issuer does not have multisig configuration");
 
         // Check if request already exists
@@ -135,30 +323,84 @@ impl MultisigCertificateContract {
             panic!("Request already exists");
         }
 
+        // Only the issuer, its signers or its configured proposers may propose
+        if proposer != issuer
+            && !config.signers.contains(&proposer)
+            && !Self::get_proposers(env.clone(), issuer.clone()).contains(&proposer)
+        {
+            panic!("Proposer is not authorized for this issuer");
+        }
+
+        // Cap the number of open requests each proposer can hold
+        let open_key = DataKey::OpenRequestCount(issuer.clone(), proposer.clone());
+        let open_requests: u32 = env.storage().instance().get(&open_key).unwrap_or(0);
+        if open_requests >= Self::get_proposer_quota(env.clone(), issuer.clone()) {
+            panic!("Proposer has too many open requests");
+        }
+        env.storage()
+            .instance()
+            .set(&open_key, &(open_requests + 1));
+
         let request = PendingRequest {
             id: request_id.clone(),
             issuer: issuer.clone(),
             recipient: recipient.clone(),
             metadata: metadata.clone(),
-            proposer: issuer.clone(),
+            proposer,
             approvals: Vec::new(&env),
             rejections: Vec::new(&env),
             rejection_reason: None,
             created_at: env.ledger().timestamp(),
-            expires_at: env.ledger().timestamp() + (expiration_days as u64 * 24 * 60 * 60), // Convert days to seconds
+            expires_at: request_window.from_now(&env),
             status: RequestStatus::Pending,
+            certificate_type,
+            executable_at: OptionalDeadline::None,
+            amendment_count: 0,
+            config_version: Self::get_multisig_config_version(env.clone(), issuer.clone()),
+            validity,
+            transfer_policy,
+            metadata_schema,
         };
 
         env.storage()
             .instance()
             .set(&DataKey::PendingRequest(request_id.clone()), &request);
+        env.storage()
+            .instance()
+            .set(&DataKey::RequestConfigSnapshot(request_id.clone()), &config);
 
-        Self::append_request_id(&env, DataKey::IssuerRequestIds(issuer), request_id.clone());
+        Self::append_request_id(
+            &env,
+            DataKey::IssuerRequestIds(issuer.clone()),
+            request_id.clone(),
+        );
+        Self::append_request_id(
+            &env,
+            DataKey::IssuerOpenRequestIds(issuer),
+            request_id.clone(),
+        );
 
         for signer in config.signers.iter() {
             Self::append_request_id(&env, DataKey::SignerRequestIds(signer), request_id.clone());
         }
 
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("proposed"),
+                request.issuer.clone(),
+            ),
+            RequestProposedEvent {
+                request_id,
+                issuer: request.issuer.clone(),
+                proposer: request.proposer.clone(),
+                recipient: request.recipient.clone(),
+                signers: config.signers.clone(),
+                threshold: config.threshold,
+                expires_at: request.expires_at.clone(),
+            },
+        );
+
         request
     }
 
@@ -166,14 +408,14 @@ impl MultisigCertificateContract {
     pub fn approve_request(env: Env, request_id: String, approver: Address) -> SignatureResult {
         approver.require_auth();
 
-        let mut request: PendingRequest = env
-            .storage()
-            .instance()
-            .get(&DataKey::PendingRequest(request_id.clone()))
-            .expect("Request not found");
+        let mut request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
 
         // Check if request has expired
-        if env.ledger().timestamp() > request.expires_at {
+        if request.expires_at.has_passed(&env) {
+            if request.status.is_open() {
+                Self::close_request(&env, &request);
+            }
             request.status = RequestStatus::Expired;
             env.storage()
                 .instance()
@@ -195,11 +437,7 @@ impl MultisigCertificateContract {
         }
 
         // Get multisig configuration
-        let config: MultisigConfig = env
-            .storage()
-            .instance()
-            .get(&DataKey::MultisigConfig(request.issuer.clone()))
-            .expect("Multisig config not found");
+        let config = Self::effective_config(&env, &mut request);
 
         // Check if approver is an authorized signer
         if !config.signers.contains(&approver) {
@@ -220,13 +458,34 @@ impl MultisigCertificateContract {
         }
 
         // Add approval
-        request.approvals.push_back(approver);
+        request.approvals.push_back(approver.clone());
 
-        // Check if threshold is reached
-        if request.approvals.len() >= config.threshold {
+        // Check if the approving weight reaches the threshold
+        if config.weight_sum(&request.approvals) >= config.threshold {
             request.status = RequestStatus::Approved;
+            // Issuance stays time-locked until the issuer's execution delay has passed
+            request.executable_at = OptionalDeadline::Some(
+                Self::get_execution_delay(env.clone(), request.issuer.clone()).from_now(&env),
+            );
         }
 
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("approved"),
+                request.issuer.clone(),
+            ),
+            RequestApprovedEvent {
+                request_id: request_id.clone(),
+                approver,
+                approval_count: request.approvals.len(),
+                approval_weight: config.weight_sum(&request.approvals),
+                threshold: config.threshold,
+                status: request.status.clone(),
+                executable_at: request.executable_at.clone(),
+            },
+        );
+
         env.storage()
             .instance()
             .set(&DataKey::PendingRequest(request_id), &request);
@@ -247,11 +506,8 @@ impl MultisigCertificateContract {
     ) -> SignatureResult {
         rejector.require_auth();
 
-        let mut request: PendingRequest = env
-            .storage()
-            .instance()
-            .get(&DataKey::PendingRequest(request_id.clone()))
-            .expect("Request not found");
+        let mut request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
 
         // Check if request is still pending
         if request.status != RequestStatus::Pending {
@@ -263,11 +519,7 @@ impl MultisigCertificateContract {
         }
 
         // Get multisig configuration
-        let config: MultisigConfig = env
-            .storage()
-            .instance()
-            .get(&DataKey::MultisigConfig(request.issuer.clone()))
-            .expect("Multisig config not found");
+        let config = Self::effective_config(&env, &mut request);
 
         // Check if rejector is an authorized signer
         if !config.signers.contains(&rejector) {
@@ -288,19 +540,34 @@ impl MultisigCertificateContract {
         }
 
         // Add rejection
-        request.rejections.push_back(rejector);
+        request.rejections.push_back(rejector.clone());
         if reason.is_some() {
             request.rejection_reason = reason;
         }
 
-        let remaining_eligible_approvers = config
-            .signers
-            .len()
-            .saturating_sub(request.rejections.len());
-        if remaining_eligible_approvers < config.threshold {
+        // Reject once the weight still able to approve can no longer reach the threshold
+        let remaining_achievable_weight = config
+            .total_weight()
+            .saturating_sub(config.weight_sum(&request.rejections));
+        if remaining_achievable_weight < config.threshold {
             request.status = RequestStatus::Rejected;
+            Self::close_request(&env, &request);
         }
 
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("rejected"),
+                request.issuer.clone(),
+            ),
+            RequestRejectedEvent {
+                request_id: request_id.clone(),
+                rejector,
+                reason: request.rejection_reason.clone(),
+                status: request.status.clone(),
+            },
+        );
+
         env.storage()
             .instance()
             .set(&DataKey::PendingRequest(request_id), &request);
@@ -312,18 +579,114 @@ impl MultisigCertificateContract {
         }
     }
 
-    /// Issue an approved certificate
-    pub fn issue_approved_certificate(env: Env, request_id: String) -> bool {
-        let mut request: PendingRequest = env
+    /// Set the delay, in ledgers or seconds, between reaching the approval threshold and
+    /// issuance
+    pub fn set_execution_delay(env: Env, issuer: Address, delay: Deadline) {
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey::PendingRequest(request_id.clone()))
-            .expect("Request not found");
+            .get(&DataKey::IssuerAdmin(issuer.clone()))
+            .expect("Issuer admin not found");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ExecutionDelay(issuer), &delay);
+    }
+
+    /// Get the execution delay for an issuer, zero ledgers if none is configured
+    pub fn get_execution_delay(env: Env, issuer: Address) -> Deadline {
+        env.storage()
+            .instance()
+            .get(&DataKey::ExecutionDelay(issuer))
+            .unwrap_or(Deadline::Ledger(0))
+    }
+
+    /// Veto an approved request while it is still time-locked (any signer can veto)
+    pub fn veto_request(
+        env: Env,
+        request_id: String,
+        signer: Address,
+        reason: Option<String>,
+    ) -> SignatureResult {
+        signer.require_auth();
+
+        let mut request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
+
+        if request.status != RequestStatus::Approved || request.executable_at.is_reached(&env) {
+            return SignatureResult {
+                success: false,
+                message: String::from_str(&env, "Request is not time-locked"),
+                final_status: OptionalRequestStatus::Some(request.status),
+            };
+        }
+
+        let config = Self::effective_config(&env, &mut request);
+        if !config.signers.contains(&signer) {
+            return SignatureResult {
+                success: false,
+                message: String::from_str(&env, "Signer is not an authorized signer"),
+                final_status: OptionalRequestStatus::Some(request.status),
+            };
+        }
+
+        request.status = RequestStatus::Vetoed;
+        Self::close_request(&env, &request);
+        request.rejections.push_back(signer.clone());
+        if reason.is_some() {
+            request.rejection_reason = reason;
+        }
+
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("vetoed"),
+                request.issuer.clone(),
+            ),
+            RequestVetoedEvent {
+                request_id: request_id.clone(),
+                signer,
+                reason: request.rejection_reason.clone(),
+            },
+        );
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingRequest(request_id), &request);
+
+        SignatureResult {
+            success: true,
+            message: String::from_str(&env, "Request vetoed"),
+            final_status: OptionalRequestStatus::Some(request.status),
+        }
+    }
+
+    /// Issue an approved certificate
+    pub fn issue_approved_certificate(env: Env, request_id: String) -> bool {
+        let mut request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
 
         if request.status != RequestStatus::Approved {
             return false;
         }
 
+        // Still inside the veto window
+        if !request.executable_at.is_reached(&env) {
+            return false;
+        }
+
+        // Signer changes since approval may leave the request below the threshold
+        let config = Self::effective_config(&env, &mut request);
+        if config.weight_sum(&request.approvals) < config.threshold {
+            request.status = RequestStatus::Pending;
+            request.executable_at = OptionalDeadline::None;
+            env.storage()
+                .instance()
+                .set(&DataKey::PendingRequest(request_id), &request);
+            return false;
+        }
+
         request.issuer.require_auth();
 
         let certificate_contract: Address = env
@@ -342,11 +705,30 @@ impl MultisigCertificateContract {
                 request.issuer.clone().into_val(&env),
                 request.recipient.clone().into_val(&env),
                 request.metadata.clone().into_val(&env),
-                Some(request.expires_at).into_val(&env),
+                request
+                    .validity
+                    .expires_at(env.ledger().timestamp())
+                    .into_val(&env),
+                request.transfer_policy.clone().into_val(&env),
+                request.metadata_schema.clone().into_val(&env),
+                request.certificate_type.clone().into_val(&env),
             ],
         );
 
         request.status = RequestStatus::Issued;
+        Self::close_request(&env, &request);
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("issued"),
+                request.issuer.clone(),
+            ),
+            RequestIssuedEvent {
+                request_id: request_id.clone(),
+                issuer: request.issuer.clone(),
+                recipient: request.recipient.clone(),
+            },
+        );
         env.storage()
             .instance()
             .set(&DataKey::PendingRequest(request_id), &request);
@@ -369,31 +751,105 @@ impl MultisigCertificateContract {
 
     /// Get a pending request by ID
     pub fn get_pending_request(env: Env, request_id: String) -> PendingRequest {
-        env.storage()
-            .instance()
-            .get(&DataKey::PendingRequest(request_id))
-            .expect("Request not found")
+        Self::load_request(&env, &request_id).expect("Request not found")
     }
 
     /// Check if a request has expired
     pub fn is_expired(env: Env, request_id: String) -> bool {
-        let request: PendingRequest = env
+        let request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
+        request.expires_at.has_passed(&env)
+    }
+
+    /// Correct the metadata or recipient of an open request (only proposer can amend).
+    /// Collected approvals and rejections are cleared so signers re-review the new content.
+    pub fn amend_request(
+        env: Env,
+        request_id: String,
+        proposer: Address,
+        new_metadata: String,
+        new_recipient: Address,
+    ) -> PendingRequest {
+        proposer.require_auth();
+
+        let mut request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
+
+        if request.proposer != proposer {
+            panic!("Only proposer can amend the request");
+        }
+
+        if !request.status.is_open() {
+            panic!("Only open requests can be amended");
+        }
+
+        if request.expires_at.has_passed(&env) {
+            panic!("Request has expired");
+        }
+
+        let history_key = DataKey::RequestAmendments(request_id.clone());
+        let mut history: Vec<RequestAmendment> = env
             .storage()
             .instance()
-            .get(&DataKey::PendingRequest(request_id))
-            .expect("Request not found");
-        env.ledger().timestamp() > request.expires_at
+            .get(&history_key)
+            .unwrap_or(Vec::new(&env));
+
+        request.amendment_count += 1;
+        history.push_back(RequestAmendment {
+            amendment: request.amendment_count,
+            previous_metadata: request.metadata.clone(),
+            previous_recipient: request.recipient.clone(),
+            amended_by: proposer,
+            amended_at: env.ledger().timestamp(),
+        });
+
+        request.metadata = new_metadata;
+        request.recipient = new_recipient;
+        request.approvals = Vec::new(&env);
+        request.rejections = Vec::new(&env);
+        request.rejection_reason = None;
+        request.executable_at = OptionalDeadline::None;
+        request.status = RequestStatus::Pending;
+
+        env.storage().instance().set(&history_key, &history);
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingRequest(request_id.clone()), &request);
+
+        let signers = Self::load_policy(&env, &request.issuer, &request.certificate_type)
+            .map(|config| config.signers)
+            .unwrap_or(Vec::new(&env));
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("amended"),
+                request.issuer.clone(),
+            ),
+            RequestAmendedEvent {
+                request_id,
+                issuer: request.issuer.clone(),
+                amendment: request.amendment_count,
+                signers,
+            },
+        );
+
+        request
+    }
+
+    /// Get the amendment history of a request, oldest first
+    pub fn get_request_amendments(env: Env, request_id: String) -> Vec<RequestAmendment> {
+        env.storage()
+            .instance()
+            .get(&DataKey::RequestAmendments(request_id))
+            .unwrap_or(Vec::new(&env))
     }
 
     /// Cancel a pending request (only proposer can cancel)
     pub fn cancel_request(env: Env, request_id: String, requester: Address) -> bool {
         requester.require_auth();
 
-        let mut request: PendingRequest = env
-            .storage()
-            .instance()
-            .get(&DataKey::PendingRequest(request_id.clone()))
-            .expect("Request not found");
+        let mut request: PendingRequest =
+            Self::load_request(&env, &request_id).expect("Request not found");
 
         if request.proposer != requester {
             panic!("Only proposer can cancel the request");
@@ -404,104 +860,268 @@ impl MultisigCertificateContract {
         }
 
         request.status = RequestStatus::Cancelled;
+        Self::close_request(&env, &request);
+        env.events().publish(
+            (
+                symbol_short!("request"),
+                symbol_short!("cancelled"),
+                request.issuer.clone(),
+            ),
+            RequestCancelledEvent {
+                request_id: request_id.clone(),
+                proposer: requester,
+            },
+        );
         env.storage()
             .instance()
             .set(&DataKey::PendingRequest(request_id), &request);
         true
     }
 
-    /// Get pending requests for an issuer (simplified pagination)
+    /// Get the requests proposed for an issuer, optionally filtered by status (cursor-paginated)
     pub fn get_pending_requests_for_issuer(
         env: Env,
         issuer: Address,
+        status: Option<RequestStatus>,
         pagination: Pagination,
     ) -> PaginatedResult {
         Self::paginate_requests(
             &env,
             Self::get_request_ids(&env, DataKey::IssuerRequestIds(issuer)),
+            status,
             pagination,
         )
     }
 
-    /// Get pending requests for a signer (simplified pagination)
+    /// Get the requests routed to a signer (open requests only), optionally filtered by status (cursor-paginated)
     pub fn get_pending_requests_for_signer(
         env: Env,
         signer: Address,
+        status: Option<RequestStatus>,
         pagination: Pagination,
     ) -> PaginatedResult {
         Self::paginate_requests(
             &env,
             Self::get_request_ids(&env, DataKey::SignerRequestIds(signer)),
+            status,
             pagination,
         )
     }
 
-    fn append_request_id(env: &Env, key: DataKey, request_id: String) {
-        let mut request_ids = Self::get_request_ids(env, key.clone());
+    /// Reject a certificate type without a policy once the issuer has named policies
+    fn require_known_type(env: &Env, issuer: &Address, certificate_type: &String) {
+        let named: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MultisigPolicyTypes(issuer.clone()))
+            .unwrap_or(Vec::new(env));
+        if !named.is_empty() && !named.contains(certificate_type) {
+            panic!("Unknown certificate type");
+        }
+    }
 
-        if !request_ids.contains(&request_id) {
-            request_ids.push_back(request_id);
-            env.storage().instance().set(&key, &request_ids);
+    /// Resolve the policy for a certificate type, falling back to the issuer's default config
+    fn load_policy(
+        env: &Env,
+        issuer: &Address,
+        certificate_type: &String,
+    ) -> Option<MultisigConfig> {
+        if let Some(policy) = env.storage().instance().get(&DataKey::MultisigPolicy(
+            issuer.clone(),
+            certificate_type.clone(),
+        )) {
+            return Some(policy);
         }
+        Self::load_config(env, issuer)
     }
 
-    fn get_request_ids(env: &Env, key: DataKey) -> Vec<String> {
-        env.storage()
+    fn load_config(env: &Env, issuer: &Address) -> Option<MultisigConfig> {
+        let storage = env.storage().instance();
+        if let Some(config) = storage.get(&DataKey::WeightedMultisigConfig(issuer.clone())) {
+            return Some(config);
+        }
+        storage
+            .get::<_, LegacyMultisigConfig>(&DataKey::MultisigConfig(issuer.clone()))
+            .map(MultisigConfig::from)
+    }
+
+    /// Requests proposed before the upgrade are stored in their legacy shape
+    fn load_request(env: &Env, request_id: &String) -> Option<PendingRequest> {
+        let entry: Val = env
+            .storage()
             .instance()
-            .get(&key)
-            .unwrap_or(Vec::<String>::new(env))
+            .get(&DataKey::PendingRequest(request_id.clone()))?;
+        PendingRequest::from_stored(env, entry)
     }
 
-    fn paginate_requests(
+    fn store_config(env: &Env, issuer: &Address, config: &MultisigConfig) {
+        let storage = env.storage().instance();
+        storage.set(&DataKey::WeightedMultisigConfig(issuer.clone()), config);
+        storage.remove(&DataKey::MultisigConfig(issuer.clone()));
+    }
+
+    fn bump_config_version(env: &Env, issuer: &Address) {
+        let version = Self::get_multisig_config_version(env.clone(), issuer.clone());
+        env.storage().instance().set(
+            &DataKey::MultisigConfigVersion(issuer.clone()),
+            &(version + 1),
+        );
+    }
+
+    /// Resolve the rules a request is evaluated under according to the issuer's rotation
+    /// policy, dropping signatures from removed signers when the current rules apply
+    fn effective_config(env: &Env, request: &mut PendingRequest) -> MultisigConfig {
+        if Self::get_signer_rotation_policy(env.clone(), request.issuer.clone())
+            == SignerRotationPolicy::KeepOriginalRules
+        {
+            if let Some(config) = env
+                .storage()
+                .instance()
+                .get(&DataKey::RequestConfigSnapshot(request.id.clone()))
+            {
+                return config;
+            }
+        }
+
+        let config = Self::load_policy(env, &request.issuer, &request.certificate_type)
+            .expect("Multisig config not found");
+        let current_version =
+            Self::get_multisig_config_version(env.clone(), request.issuer.clone());
+        if request.config_version != current_version {
+            request.approvals = Self::retain_signers(env, &config, &request.approvals);
+            request.rejections = Self::retain_signers(env, &config, &request.rejections);
+            request.config_version = current_version;
+        }
+        config
+    }
+
+    fn retain_signers(
         env: &Env,
-        request_ids: Vec<String>,
-        pagination: Pagination,
-    ) -> PaginatedResult {
-        let mut pending_requests = Vec::<PendingRequest>::new(env);
+        config: &MultisigConfig,
+        addresses: &Vec<Address>,
+    ) -> Vec<Address> {
+        let mut retained = Vec::new(env);
+        for address in addresses.iter() {
+            if config.signers.contains(&address) {
+                retained.push_back(address);
+            }
+        }
+        retained
+    }
+
+    /// Bookkeeping once a request leaves the open states: free the proposer's quota slot,
+    /// drop the request from the inboxes of the signers it was routed to and discard its
+    /// config snapshot
+    fn close_request(env: &Env, request: &PendingRequest) {
+        let key = DataKey::OpenRequestCount(request.issuer.clone(), request.proposer.clone());
+        let open_requests: u32 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&key, &open_requests.saturating_sub(1));
 
-        for request_id in request_ids.iter() {
-            if let Some(request) = env
+        Self::remove_request_id(
+            env,
+            DataKey::IssuerOpenRequestIds(request.issuer.clone()),
+            &request.id,
+        );
+        for signer in Self::request_signers(env, request).iter() {
+            Self::remove_request_id(env, DataKey::SignerRequestIds(signer), &request.id);
+        }
+        env.storage()
+            .instance()
+            .remove(&DataKey::RequestConfigSnapshot(request.id.clone()));
+    }
+
+    /// Signers a request is routed to: its snapshot under `KeepOriginalRules`, otherwise
+    /// the issuer's current policy for the request's certificate type
+    fn request_signers(env: &Env, request: &PendingRequest) -> Vec<Address> {
+        if Self::get_signer_rotation_policy(env.clone(), request.issuer.clone())
+            == SignerRotationPolicy::KeepOriginalRules
+        {
+            if let Some(config) = env
                 .storage()
                 .instance()
-                .get::<_, PendingRequest>(&DataKey::PendingRequest(request_id))
+                .get::<_, MultisigConfig>(&DataKey::RequestConfigSnapshot(request.id.clone()))
             {
-                if request.status == RequestStatus::Pending {
-                    pending_requests.push_back(request);
+                return config.signers;
+            }
+        }
+        Self::load_policy(env, &request.issuer, &request.certificate_type)
+            .map(|config| config.signers)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Signers each open request of the issuer is currently routed to
+    fn open_request_routes(env: &Env, issuer: &Address) -> Map<String, Vec<Address>> {
+        let mut routes = Map::new(env);
+        for request_id in
+            Self::get_request_ids(env, DataKey::IssuerOpenRequestIds(issuer.clone())).iter()
+        {
+            if let Some(request) = Self::load_request(env, &request_id) {
+                routes.set(request_id, Self::request_signers(env, &request));
+            }
+        }
+        routes
+    }
+
+    /// Move open requests between signer inboxes after the rules that route them change
+    fn reroute_open_requests(env: &Env, routes: &Map<String, Vec<Address>>) {
+        for (request_id, previous) in routes.iter() {
+            let request: PendingRequest =
+                Self::load_request(env, &request_id).expect("Request not found");
+            let current = Self::request_signers(env, &request);
+            for signer in previous.iter() {
+                if !current.contains(&signer) {
+                    Self::remove_request_id(env, DataKey::SignerRequestIds(signer), &request_id);
                 }
             }
+            for signer in current.iter() {
+                Self::append_request_id(env, DataKey::SignerRequestIds(signer), request_id.clone());
+            }
         }
+    }
 
-        let total = pending_requests.len();
-        let mut page_data = Vec::<PendingRequest>::new(env);
+    fn append_request_id(env: &Env, key: DataKey, request_id: String) {
+        let mut request_ids = Self::get_request_ids(env, key.clone());
 
-        if pagination.limit == 0 {
-            return PaginatedResult {
-                data: page_data,
-                total,
-                page: pagination.page,
-                limit: pagination.limit,
-                has_next: false,
-            };
+        if !request_ids.contains(&request_id) {
+            request_ids.push_back(request_id);
+            env.storage().instance().set(&key, &request_ids);
         }
+    }
 
-        // Page is 1-indexed. Calculate start index (0-indexed)
-        let start = pagination.page.saturating_sub(1).saturating_mul(pagination.limit);
-        let end = total.min(start.saturating_add(pagination.limit));
+    fn remove_request_id(env: &Env, key: DataKey, request_id: &String) {
+        let mut request_ids = Self::get_request_ids(env, key.clone());
 
-        let mut index = start;
-        while index < end {
-            if let Some(request) = pending_requests.get(index) {
-                page_data.push_back(request);
-            }
-            index += 1;
+        if let Some(index) = request_ids.first_index_of(request_id) {
+            request_ids.remove(index);
+            env.storage().instance().set(&key, &request_ids);
         }
+    }
+
+    fn get_request_ids(env: &Env, key: DataKey) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&key)
+            .unwrap_or(Vec::<String>::new(env))
+    }
+
+    fn paginate_requests(
+        env: &Env,
+        request_ids: Vec<String>,
+        status: Option<RequestStatus>,
+        pagination: Pagination,
+    ) -> PaginatedResult {
+        let (data, next_cursor) = pagination.collect(env, &request_ids, |request_id| {
+            Self::load_request(env, &request_id)
+                .map(|request| request.with_lapsed_expiry(env))
+                .filter(|request| status.is_none() || status.as_ref() == Some(&request.status))
+        });
 
         PaginatedResult {
-            data: page_data,
-            total,
-            page: pagination.page,
+            data,
             limit: pagination.limit,
-            has_next: end < total,
+            next_cursor,
         }
     }
 }
@@ -1,7 +1,17 @@
 #![cfg(test)]
 use super::multisig::*;
-use crate::{CertificateContract, CertificateContractClient, OptionalRequestStatus, Pagination, RequestStatus};
-use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+use crate::{
+    CertificateContract, CertificateContractClient, CertificateValidity, DataKey, Deadline,
+    LegacyMultisigConfig, LegacyPendingRequest, OptionalDeadline, OptionalRequestStatus,
+    Pagination, RequestAmendedEvent, RequestApprovedEvent, RequestCancelledEvent,
+    RequestIssuedEvent, RequestProposedEvent, RequestRejectedEvent, RequestStatus,
+    SignerRotationPolicy, TransferPolicy,
+};
+use soroban_sdk::{
+    map, symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, String, Vec,
+};
 
 #[test]
 fn test_init_multisig_config() {
@@ -17,7 +27,7 @@ fn test_init_multisig_config() {
     let signers = vec![&env, signer1.clone(), signer2.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &2, &signers, &5, &admin);
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
 
     let config = client.get_multisig_config(&issuer);
     assert_eq!(config.threshold, 2);
@@ -40,12 +50,23 @@ fn test_propose_certificate() {
     let signers = vec![&env, signer1.clone(), signer2.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &2, &signers, &5, &admin);
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
 
     let request_id = String::from_str(&env, "req-001");
     let metadata = String::from_str(&env, "certificate metadata");
 
-    let request = client.propose_certificate(&request_id, &issuer, &recipient, &metadata, &7);
+    let request = client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
 
     assert_eq!(request.id, request_id);
     assert_eq!(request.issuer, issuer);
@@ -70,12 +91,23 @@ fn test_approve_request_success() {
     let signers = vec![&env, signer1.clone(), signer2.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &2, &signers, &5, &admin);
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
 
     let request_id = String::from_str(&env, "req-002");
     let metadata = String::from_str(&env, "certificate metadata");
 
-    client.propose_certificate(&request_id, &issuer, &recipient, &metadata, &7);
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
 
     // First approval
     let result = client.approve_request(&request_id, &signer1);
@@ -116,12 +148,23 @@ fn test_reject_request() {
     let signers = vec![&env, signer1.clone(), signer2.clone(), signer3.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &2, &signers, &5, &admin);
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
 
     let request_id = String::from_str(&env, "req-003");
     let metadata = String::from_str(&env, "certificate metadata");
 
-    client.propose_certificate(&request_id, &issuer, &recipient, &metadata, &7);
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
 
     // Reject by one signer
     let rejection_reason = String::from_str(&env, "Insufficient supporting documentation");
@@ -174,12 +217,23 @@ fn test_reject_request_impossible_approval() {
     let signers = vec![&env, signer1.clone(), signer2.clone(), signer3.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &3, &signers, &5, &admin);
+    client.init_multisig_config(&issuer, &3, &signers, &5, &None, &admin);
 
     let request_id = String::from_str(&env, "req-004");
     let metadata = String::from_str(&env, "certificate metadata");
 
-    client.propose_certificate(&request_id, &issuer, &recipient, &metadata, &7);
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
 
     // Reject by one signer - with a 3-of-3 config this already makes approval impossible
     let result = client.reject_request(&request_id, &signer1, &None);
@@ -211,12 +265,23 @@ fn test_issue_approved_certificate() {
     let signers = vec![&env, signer1.clone(), signer2.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &2, &signers, &5, &admin);
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
 
     let request_id = String::from_str(&env, "req-005");
     let metadata = String::from_str(&env, "certificate metadata");
 
-    client.propose_certificate(&request_id, &issuer, &recipient, &metadata, &7);
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
 
     // Get both approvals
     client.approve_request(&request_id, &signer1);
@@ -258,12 +323,23 @@ fn test_cancel_request() {
     let signers = vec![&env, signer1.clone(), signer2.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &2, &signers, &5, &admin);
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
 
     let request_id = String::from_str(&env, "req-006");
     let metadata = String::from_str(&env, "certificate metadata");
 
-    client.propose_certificate(&request_id, &issuer, &recipient, &metadata, &7);
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
 
     // Cancel the request (proposer is the issuer in our implementation)
     let success = client.cancel_request(&request_id, &issuer);
@@ -271,7 +347,7 @@ fn test_cancel_request() {
 
     // Check the request status
     let request = client.get_pending_request(&request_id);
-    assert_eq!(request.status, RequestStatus::Rejected); // Using Rejected as cancelled
+    assert_eq!(request.status, RequestStatus::Cancelled);
 }
 
 #[test]
@@ -288,11 +364,11 @@ fn test_update_multisig_config() {
     let initial_signers = vec![&env, signer1.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &1, &initial_signers, &5, &admin);
+    client.init_multisig_config(&issuer, &1, &initial_signers, &5, &None, &admin);
 
     // Update the config
     let new_signers = vec![&env, signer1.clone(), signer2.clone()];
-    client.update_multisig_config(&issuer, &Some(2), &Some(new_signers), &Some(10));
+    client.update_multisig_config(&issuer, &Some(2), &Some(new_signers), &Some(10), &None);
 
     let config = client.get_multisig_config(&issuer);
     assert_eq!(config.threshold, 2);
@@ -315,12 +391,23 @@ fn test_invalid_approve_by_non_signer() {
     let signers = vec![&env, signer1.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &1, &signers, &5, &admin);
+    client.init_multisig_config(&issuer, &1, &signers, &5, &None, &admin);
 
     let request_id = String::from_str(&env, "req-007");
     let metadata = String::from_str(&env, "certificate metadata");
 
-    client.propose_certificate(&request_id, &issuer, &recipient, &metadata, &7);
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
 
     // Try to approve with non-signer - should fail
     let result = client.approve_request(&request_id, &non_signer);
@@ -346,12 +433,23 @@ fn test_double_approval() {
     let signers = vec![&env, signer1.clone(), signer2.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &2, &signers, &5, &admin);
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
 
     let request_id = String::from_str(&env, "req-008");
     let metadata = String::from_str(&env, "certificate metadata");
 
-    client.propose_certificate(&request_id, &issuer, &recipient, &metadata, &7);
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
 
     // First approval
     let result = client.approve_request(&request_id, &signer1);
@@ -380,13 +478,24 @@ fn test_expired_request() {
     let signers = vec![&env, signer1.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &1, &signers, &5, &admin);
+    client.init_multisig_config(&issuer, &1, &signers, &5, &None, &admin);
 
     let request_id = String::from_str(&env, "req-009");
     let metadata = String::from_str(&env, "certificate metadata");
 
     // Create request with 1 day expiration
-    client.propose_certificate(&request_id, &issuer, &recipient, &metadata, &1);
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &metadata,
+        &Deadline::days(1),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
 
     // Manually advance time to expire the request (in a real test, we'd use ledger time)
     // This is a simplified test - in reality we'd check the expiration in the contract
@@ -410,32 +519,134 @@ fn test_get_pending_requests_for_issuer_returns_paginated_results() {
     let signers = vec![&env, signer1.clone(), signer2.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &2, &signers, &5, &admin);
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
 
     for id in ["req-issuer-1", "req-issuer-2", "req-issuer-3"] {
         client.propose_certificate(
             &String::from_str(&env, id),
             &issuer,
+            &issuer,
             &recipient,
             &String::from_str(&env, "certificate metadata"),
-            &7,
+            &Deadline::days(7),
+            &String::from_str(&env, "diploma"),
+            &CertificateValidity::Permanent,
+            &TransferPolicy::FreelyTransferable,
+            &None,
         );
     }
 
     client.approve_request(&String::from_str(&env, "req-issuer-2"), &signer1);
     client.approve_request(&String::from_str(&env, "req-issuer-2"), &signer2);
 
-    let first_page =
-        client.get_pending_requests_for_issuer(&issuer, &Pagination { page: 1, limit: 1 });
-    assert_eq!(first_page.total, 2);
+    let pending = Some(RequestStatus::Pending);
+    let first_page = client.get_pending_requests_for_issuer(
+        &issuer,
+        &pending,
+        &Pagination {
+            cursor: 0,
+            limit: 1,
+        },
+    );
     assert_eq!(first_page.data.len(), 1);
-    assert!(first_page.has_next);
-
-    let second_page =
-        client.get_pending_requests_for_issuer(&issuer, &Pagination { page: 2, limit: 1 });
-    assert_eq!(second_page.total, 2);
+    assert_eq!(first_page.next_cursor, Some(1));
+
+    let second_page = client.get_pending_requests_for_issuer(
+        &issuer,
+        &pending,
+        &Pagination {
+            cursor: first_page.next_cursor.unwrap(),
+            limit: 1,
+        },
+    );
     assert_eq!(second_page.data.len(), 1);
-    assert!(!second_page.has_next);
+    assert_eq!(
+        second_page.data.get(0).unwrap().id,
+        String::from_str(&env, "req-issuer-3")
+    );
+    assert_eq!(second_page.next_cursor, None);
+
+    // Without a filter the issuer sees every request, including approved ones
+    let all = client.get_pending_requests_for_issuer(
+        &issuer,
+        &None,
+        &Pagination {
+            cursor: 0,
+            limit: 10,
+        },
+    );
+    assert_eq!(all.data.len(), 3);
+
+    // A zero limit would return the same cursor forever
+    let result = client.try_get_pending_requests_for_issuer(
+        &issuer,
+        &None,
+        &Pagination {
+            cursor: 0,
+            limit: 0,
+        },
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_lapsed_requests_listed_as_expired() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_multisig_config(
+        &issuer,
+        &2,
+        &vec![&env, signer1.clone(), signer2.clone()],
+        &5,
+        &None,
+        &admin,
+    );
+
+    for (id, days) in [("req-short", 1), ("req-long", 7)] {
+        client.propose_certificate(
+            &String::from_str(&env, id),
+            &issuer,
+            &issuer,
+            &recipient,
+            &String::from_str(&env, "certificate metadata"),
+            &Deadline::days(days),
+            &String::from_str(&env, "diploma"),
+            &CertificateValidity::Permanent,
+            &TransferPolicy::FreelyTransferable,
+            &None,
+        );
+    }
+
+    env.ledger().with_mut(|li| li.timestamp += 2 * 24 * 60 * 60);
+
+    let page = Pagination {
+        cursor: 0,
+        limit: 10,
+    };
+    let pending =
+        client.get_pending_requests_for_signer(&signer1, &Some(RequestStatus::Pending), &page);
+    assert_eq!(pending.data.len(), 1);
+    assert_eq!(
+        pending.data.get(0).unwrap().id,
+        String::from_str(&env, "req-long")
+    );
+
+    let expired =
+        client.get_pending_requests_for_issuer(&issuer, &Some(RequestStatus::Expired), &page);
+    assert_eq!(expired.data.len(), 1);
+    assert_eq!(
+        expired.data.get(0).unwrap().id,
+        String::from_str(&env, "req-short")
+    );
 }
 
 #[test]
@@ -454,28 +665,1851 @@ fn test_get_pending_requests_for_signer_returns_only_pending_requests() {
     let signers = vec![&env, signer1.clone(), signer2.clone(), signer3.clone()];
 
     env.mock_all_auths();
-    client.init_multisig_config(&issuer, &2, &signers, &5, &admin);
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
 
     for id in ["req-signer-1", "req-signer-2", "req-signer-3"] {
         client.propose_certificate(
             &String::from_str(&env, id),
             &issuer,
+            &issuer,
             &recipient,
             &String::from_str(&env, "certificate metadata"),
-            &7,
+            &Deadline::days(7),
+            &String::from_str(&env, "diploma"),
+            &CertificateValidity::Permanent,
+            &TransferPolicy::FreelyTransferable,
+            &None,
         );
     }
 
     client.reject_request(&String::from_str(&env, "req-signer-2"), &signer1, &None);
     client.reject_request(&String::from_str(&env, "req-signer-2"), &signer2, &None);
 
-    let requests =
-        client.get_pending_requests_for_signer(&signer3, &Pagination { page: 1, limit: 10 });
+    // The rejected request is pruned from the signer's inbox
+    let requests = client.get_pending_requests_for_signer(
+        &signer3,
+        &None,
+        &Pagination {
+            cursor: 0,
+            limit: 10,
+        },
+    );
 
-    assert_eq!(requests.total, 2);
     assert_eq!(requests.data.len(), 2);
+    assert_eq!(requests.next_cursor, None);
     assert!(requests
         .data
         .iter()
         .all(|request| request.status == RequestStatus::Pending));
 }
+
+#[test]
+fn test_weighted_signer_reaches_threshold_alone() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let registrar = Address::generate(&env);
+    let dept_head1 = Address::generate(&env);
+    let dept_head2 = Address::generate(&env);
+
+    let signers = vec![
+        &env,
+        registrar.clone(),
+        dept_head1.clone(),
+        dept_head2.clone(),
+    ];
+    let weights = map![&env, (registrar.clone(), 2)];
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &2, &signers, &5, &Some(weights), &admin);
+
+    let config = client.get_multisig_config(&issuer);
+    assert_eq!(config.weight_of(&registrar), 2);
+    assert_eq!(config.weight_of(&dept_head1), 1);
+    assert_eq!(config.total_weight(), 4);
+
+    // The registrar's signature counts double and meets the threshold alone
+    let request_id = String::from_str(&env, "req-weighted-1");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    let result = client.approve_request(&request_id, &registrar);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Approved)
+    );
+
+    // A single department head does not
+    let request_id = String::from_str(&env, "req-weighted-2");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    let result = client.approve_request(&request_id, &dept_head1);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Pending)
+    );
+    let result = client.approve_request(&request_id, &dept_head2);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Approved)
+    );
+}
+
+#[test]
+fn test_weighted_rejection_when_threshold_unreachable() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let registrar = Address::generate(&env);
+    let dept_head1 = Address::generate(&env);
+    let dept_head2 = Address::generate(&env);
+
+    let signers = vec![
+        &env,
+        registrar.clone(),
+        dept_head1.clone(),
+        dept_head2.clone(),
+    ];
+    let weights = map![&env, (registrar.clone(), 2)];
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &3, &signers, &5, &Some(weights), &admin);
+
+    let request_id = String::from_str(&env, "req-weighted-reject");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+
+    // Losing one department head leaves weight 3, still enough
+    let result = client.reject_request(&request_id, &dept_head1, &None);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Pending)
+    );
+
+    // Losing the registrar leaves weight 1, below the threshold of 3
+    let result = client.reject_request(&request_id, &registrar, &None);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Rejected)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid multisig parameters")]
+fn test_threshold_above_total_weight_rejected() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+
+    let signers = vec![&env, signer1.clone(), signer2.clone()];
+    let weights = map![&env, (signer1.clone(), 2)];
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &4, &signers, &5, &Some(weights), &admin);
+}
+
+#[test]
+fn test_weighted_threshold_may_exceed_signer_count() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let registrar = Address::generate(&env);
+    let head1 = Address::generate(&env);
+    let head2 = Address::generate(&env);
+
+    let signers = vec![&env, registrar.clone(), head1.clone(), head2.clone()];
+    let weights = map![&env, (registrar.clone(), 2)];
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &4, &signers, &3, &Some(weights), &admin);
+
+    let config = client.get_multisig_config(&issuer);
+    assert_eq!(config.threshold, 4);
+    assert_eq!(config.total_weight(), 4);
+}
+
+#[test]
+#[should_panic(expected = "Invalid multisig parameters")]
+fn test_more_signers_than_max_rejected() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let signers = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &1, &signers, &2, &None, &admin);
+}
+
+#[test]
+#[should_panic(expected = "Invalid multisig parameters")]
+fn test_duplicate_signers_rejected() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+
+    // signer1 listed twice would make a threshold of 3 look reachable
+    let signers = vec![&env, signer1.clone(), signer1, signer2];
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &3, &signers, &5, &None, &admin);
+}
+
+#[test]
+fn test_legacy_config_migrates_with_unit_weights() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+
+    // Seed storage with a config written before signer weights existed
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(
+            &DataKey::MultisigConfig(issuer.clone()),
+            &LegacyMultisigConfig {
+                threshold: 2,
+                signers: vec![&env, signer1.clone(), signer2.clone()],
+                max_signers: 5,
+            },
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::IssuerAdmin(issuer.clone()), &admin);
+    });
+
+    // Legacy configs are readable with weight 1 per signer
+    let config = client.get_multisig_config(&issuer);
+    assert_eq!(config.weight_of(&signer1), 1);
+    assert_eq!(config.weight_of(&signer2), 1);
+    assert_eq!(config.total_weight(), 2);
+
+    env.mock_all_auths();
+    client.migrate_multisig_config(&issuer);
+
+    env.as_contract(&contract_id, || {
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&DataKey::MultisigConfig(issuer.clone())));
+        assert!(env
+            .storage()
+            .instance()
+            .has(&DataKey::WeightedMultisigConfig(issuer.clone())));
+    });
+    assert_eq!(client.get_multisig_config(&issuer).threshold, 2);
+}
+
+#[test]
+fn test_certificate_type_policy_selects_signers() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let clerk = Address::generate(&env);
+    let dean = Address::generate(&env);
+    let registrar = Address::generate(&env);
+
+    env.mock_all_auths();
+    // Transcripts need a single clerk signature under the default config
+    client.init_multisig_config(&issuer, &1, &vec![&env, clerk.clone()], &5, &None, &admin);
+    let transcript_id = String::from_str(&env, "req-transcript");
+    client.propose_certificate(
+        &transcript_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "transcript metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "transcript"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+
+    // Degrees need both the dean and the registrar
+    let degree = String::from_str(&env, "degree");
+    client.set_multisig_policy(
+        &issuer,
+        &degree,
+        &2,
+        &vec![&env, dean.clone(), registrar.clone()],
+        &5,
+        &None,
+    );
+    assert_eq!(
+        client.get_multisig_policy_types(&issuer),
+        vec![&env, degree.clone()]
+    );
+    assert_eq!(client.get_multisig_policy(&issuer, &degree).threshold, 2);
+
+    let degree_id = String::from_str(&env, "req-degree");
+    let request = client.propose_certificate(
+        &degree_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "degree metadata"),
+        &Deadline::days(7),
+        &degree,
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    assert_eq!(request.certificate_type, degree);
+
+    // Only the signers of the selected policy see each request
+    let clerk_inbox = client.get_pending_requests_for_signer(
+        &clerk,
+        &None,
+        &Pagination {
+            cursor: 0,
+            limit: 10,
+        },
+    );
+    assert_eq!(clerk_inbox.data.len(), 1);
+    assert_eq!(clerk_inbox.data.get(0).unwrap().id, transcript_id);
+    let dean_inbox = client.get_pending_requests_for_signer(
+        &dean,
+        &None,
+        &Pagination {
+            cursor: 0,
+            limit: 10,
+        },
+    );
+    assert_eq!(dean_inbox.data.len(), 1);
+    assert_eq!(dean_inbox.data.get(0).unwrap().id, degree_id);
+
+    // The default signer cannot approve a degree
+    let result = client.approve_request(&degree_id, &clerk);
+    assert!(!result.success);
+
+    let result = client.approve_request(&degree_id, &dean);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Pending)
+    );
+    let result = client.approve_request(&degree_id, &registrar);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Approved)
+    );
+
+    // Removing the policy sends new degree proposals back to the default config
+    client.remove_multisig_policy(&issuer, &degree);
+    assert_eq!(client.get_multisig_policy_types(&issuer).len(), 0);
+    let fallback_id = String::from_str(&env, "req-degree-fallback");
+    client.propose_certificate(
+        &fallback_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "degree metadata"),
+        &Deadline::days(7),
+        &degree,
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    let result = client.approve_request(&fallback_id, &clerk);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Approved)
+    );
+}
+
+#[test]
+fn test_unknown_certificate_type_rejected_once_policies_exist() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let clerk = Address::generate(&env);
+    let dean = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &1, &vec![&env, clerk.clone()], &5, &None, &admin);
+    client.set_multisig_policy(
+        &issuer,
+        &String::from_str(&env, "degree"),
+        &1,
+        &vec![&env, dean.clone()],
+        &5,
+        &None,
+    );
+
+    // A misspelled type must not silently fall back to the clerk-only default
+    let result = client.try_propose_certificate(
+        &String::from_str(&env, "req-typo"),
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "degree metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "degre"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    assert!(result.is_err());
+
+    // Removing a policy sends requests already proposed under it back to the default config
+    let degree_id = String::from_str(&env, "req-degree");
+    client.propose_certificate(
+        &degree_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "degree metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "degree"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    client.set_multisig_policy(
+        &issuer,
+        &String::from_str(&env, "transcript"),
+        &1,
+        &vec![&env, clerk.clone()],
+        &5,
+        &None,
+    );
+    client.remove_multisig_policy(&issuer, &String::from_str(&env, "degree"));
+    let result = client.approve_request(&degree_id, &clerk);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Approved)
+    );
+}
+
+#[test]
+fn test_execution_delay_time_locks_issuance() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+
+    let signers = vec![&env, signer1.clone(), signer2.clone()];
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &1, &signers, &5, &None, &admin);
+    client.set_execution_delay(&issuer, &Deadline::days(2));
+    assert_eq!(client.get_execution_delay(&issuer), Deadline::days(2));
+
+    let certificate_contract_id = env.register_contract(None, CertificateContract);
+    let certificate_client = CertificateContractClient::new(&env, &certificate_contract_id);
+    certificate_client.initialize(&admin);
+    certificate_client.add_issuer(&issuer);
+    client.set_certificate_contract(&admin, &certificate_contract_id);
+
+    let request_id = String::from_str(&env, "req-timelock");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    client.approve_request(&request_id, &signer1);
+
+    let request = client.get_pending_request(&request_id);
+    assert_eq!(request.status, RequestStatus::Approved);
+    assert_eq!(
+        request.executable_at,
+        OptionalDeadline::Some(Deadline::Timestamp(
+            env.ledger().timestamp() + 2 * 24 * 60 * 60
+        ))
+    );
+
+    // Issuance is blocked during the review window
+    assert!(!client.issue_approved_certificate(&request_id));
+    assert!(!certificate_client.certificate_exists(&request_id));
+
+    env.ledger().with_mut(|li| li.timestamp += 2 * 24 * 60 * 60);
+
+    // The window has closed: no more vetoes, issuance goes through
+    let result = client.veto_request(&request_id, &signer2, &None);
+    assert!(!result.success);
+    assert!(client.issue_approved_certificate(&request_id));
+    assert!(certificate_client.certificate_exists(&request_id));
+}
+
+#[test]
+fn test_execution_delay_counted_in_ledgers() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_multisig_config(
+        &issuer,
+        &1,
+        &vec![&env, signer1.clone(), signer2.clone()],
+        &5,
+        &None,
+        &admin,
+    );
+    client.set_execution_delay(&issuer, &Deadline::Ledger(100));
+
+    let request_id = String::from_str(&env, "req-ledger-lock");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    client.approve_request(&request_id, &signer1);
+    assert_eq!(
+        client.get_pending_request(&request_id).executable_at,
+        OptionalDeadline::Some(Deadline::Ledger(env.ledger().sequence() + 100))
+    );
+
+    // Elapsed time alone does not open a ledger-counted lock
+    env.ledger().with_mut(|li| li.timestamp += 7 * 24 * 60 * 60);
+    assert!(client.veto_request(&request_id, &signer2, &None).success);
+}
+
+#[test]
+fn test_signer_can_veto_time_locked_request() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let compliance = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    let signers = vec![&env, signer1.clone(), compliance.clone()];
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &1, &signers, &5, &None, &admin);
+    client.set_execution_delay(&issuer, &Deadline::days(1));
+
+    let request_id = String::from_str(&env, "req-veto");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    client.approve_request(&request_id, &signer1);
+
+    let result = client.veto_request(&request_id, &outsider, &None);
+    assert!(!result.success);
+
+    let reason = String::from_str(&env, "failed compliance review");
+    let result = client.veto_request(&request_id, &compliance, &Some(reason.clone()));
+    assert!(result.success);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Vetoed)
+    );
+
+    let request = client.get_pending_request(&request_id);
+    assert_eq!(request.status, RequestStatus::Vetoed);
+    assert_eq!(request.rejection_reason, Some(reason));
+
+    env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+    assert!(!client.issue_approved_certificate(&request_id));
+}
+
+#[test]
+#[should_panic(expected = "Proposer is not authorized for this issuer")]
+fn test_unauthorized_proposer_cannot_propose() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &1, &vec![&env, signer1], &5, &None, &admin);
+
+    client.propose_certificate(
+        &String::from_str(&env, "req-outsider"),
+        &issuer,
+        &outsider,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+}
+
+#[test]
+fn test_configured_proposer_and_signer_can_propose() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let registrar = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &1, &vec![&env, signer1.clone()], &5, &None, &admin);
+    client.set_proposers(&issuer, &vec![&env, registrar.clone()]);
+    assert_eq!(client.get_proposers(&issuer), vec![&env, registrar.clone()]);
+
+    let metadata = String::from_str(&env, "certificate metadata");
+    let request = client.propose_certificate(
+        &String::from_str(&env, "req-registrar"),
+        &issuer,
+        &registrar,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    assert_eq!(request.proposer, registrar);
+
+    let request = client.propose_certificate(
+        &String::from_str(&env, "req-signer"),
+        &issuer,
+        &signer1,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    assert_eq!(request.proposer, signer1);
+
+    // Only the actual proposer can cancel their request
+    assert!(client
+        .try_cancel_request(&String::from_str(&env, "req-signer"), &issuer)
+        .is_err());
+    assert!(client.cancel_request(&String::from_str(&env, "req-signer"), &signer1));
+}
+
+#[test]
+fn test_proposer_quota_limits_open_requests() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &1, &vec![&env, signer1.clone()], &5, &None, &admin);
+    client.set_proposer_quota(&issuer, &2);
+
+    let metadata = String::from_str(&env, "certificate metadata");
+    for id in ["req-q1", "req-q2"] {
+        client.propose_certificate(
+            &String::from_str(&env, id),
+            &issuer,
+            &signer1,
+            &recipient,
+            &metadata,
+            &Deadline::days(7),
+            &String::from_str(&env, "diploma"),
+            &CertificateValidity::Permanent,
+            &TransferPolicy::FreelyTransferable,
+            &None,
+        );
+    }
+    assert_eq!(client.get_open_request_count(&issuer, &signer1), 2);
+
+    let result = client.try_propose_certificate(
+        &String::from_str(&env, "req-q3"),
+        &issuer,
+        &signer1,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    assert!(result.is_err());
+
+    // Closing a request frees a slot for the proposer
+    client.cancel_request(&String::from_str(&env, "req-q1"), &signer1);
+    assert_eq!(client.get_open_request_count(&issuer, &signer1), 1);
+
+    client.propose_certificate(
+        &String::from_str(&env, "req-q3"),
+        &issuer,
+        &signer1,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    assert_eq!(client.get_open_request_count(&issuer, &signer1), 2);
+}
+
+#[test]
+fn test_amend_request_resets_review() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let corrected_recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+
+    let signers = vec![&env, signer1.clone(), signer2.clone()];
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
+
+    let request_id = String::from_str(&env, "req-amend");
+    let original_metadata = String::from_str(&env, "Bachelor of Sciense");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &original_metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    client.approve_request(&request_id, &signer1);
+
+    let corrected_metadata = String::from_str(&env, "Bachelor of Science");
+    let request = client.amend_request(
+        &request_id,
+        &issuer,
+        &corrected_metadata,
+        &corrected_recipient,
+    );
+    assert_eq!(request.metadata, corrected_metadata);
+    assert_eq!(request.recipient, corrected_recipient);
+    assert_eq!(request.status, RequestStatus::Pending);
+    assert_eq!(request.approvals.len(), 0);
+    assert_eq!(request.amendment_count, 1);
+
+    let last_event = env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        (
+            symbol_short!("request"),
+            symbol_short!("amended"),
+            issuer.clone()
+        )
+            .into_val(&env)
+    );
+    let event: RequestAmendedEvent = last_event.2.into_val(&env);
+    assert_eq!(event.amendment, 1);
+    assert_eq!(event.signers, signers);
+
+    let history = client.get_request_amendments(&request_id);
+    assert_eq!(history.len(), 1);
+    let entry = history.get(0).unwrap();
+    assert_eq!(entry.previous_metadata, original_metadata);
+    assert_eq!(entry.previous_recipient, recipient);
+    assert_eq!(entry.amended_by, issuer);
+
+    // Earlier approvals no longer count: both signers must approve again
+    let result = client.approve_request(&request_id, &signer1);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Pending)
+    );
+    let result = client.approve_request(&request_id, &signer2);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Approved)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Only proposer can amend the request")]
+fn test_only_proposer_can_amend_request() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &1, &vec![&env, signer1.clone()], &5, &None, &admin);
+
+    let request_id = String::from_str(&env, "req-amend");
+    let metadata = String::from_str(&env, "certificate metadata");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    client.amend_request(&request_id, &signer1, &metadata, &recipient);
+}
+
+#[test]
+fn test_removed_signer_approvals_are_dropped() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let signer3 = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_multisig_config(
+        &issuer,
+        &2,
+        &vec![&env, signer1.clone(), signer2.clone(), signer3.clone()],
+        &5,
+        &None,
+        &admin,
+    );
+
+    let request_id = String::from_str(&env, "req-rotation");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    client.approve_request(&request_id, &signer1);
+    assert_eq!(client.get_pending_request(&request_id).config_version, 0);
+
+    // signer1 is rotated out while the request is in flight
+    client.update_multisig_config(
+        &issuer,
+        &None,
+        &Some(vec![&env, signer2.clone(), signer3.clone()]),
+        &None,
+        &None,
+    );
+    assert_eq!(client.get_multisig_config_version(&issuer), 1);
+
+    let result = client.approve_request(&request_id, &signer2);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Pending)
+    );
+    let request = client.get_pending_request(&request_id);
+    assert_eq!(request.approvals, vec![&env, signer2.clone()]);
+    assert_eq!(request.config_version, 1);
+
+    let result = client.approve_request(&request_id, &signer3);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Approved)
+    );
+}
+
+#[test]
+fn test_raised_threshold_blocks_issuance_of_approved_request() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+
+    let signers = vec![&env, signer1.clone(), signer2.clone()];
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &1, &signers, &5, &None, &admin);
+
+    let request_id = String::from_str(&env, "req-threshold");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    client.approve_request(&request_id, &signer1);
+    assert_eq!(
+        client.get_pending_request(&request_id).status,
+        RequestStatus::Approved
+    );
+
+    client.update_multisig_config(&issuer, &Some(2), &None, &None, &None);
+
+    // The approval no longer meets the current threshold
+    assert!(!client.issue_approved_certificate(&request_id));
+    let request = client.get_pending_request(&request_id);
+    assert_eq!(request.status, RequestStatus::Pending);
+    assert_eq!(request.executable_at, OptionalDeadline::None);
+
+    let result = client.approve_request(&request_id, &signer2);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Approved)
+    );
+}
+
+#[test]
+fn test_keep_original_rules_policy() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let signer3 = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_multisig_config(
+        &issuer,
+        &2,
+        &vec![&env, signer1.clone(), signer2.clone()],
+        &5,
+        &None,
+        &admin,
+    );
+    client.set_signer_rotation_policy(&issuer, &SignerRotationPolicy::KeepOriginalRules);
+
+    let request_id = String::from_str(&env, "req-original");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    client.approve_request(&request_id, &signer1);
+
+    client.update_multisig_config(
+        &issuer,
+        &Some(1),
+        &Some(vec![&env, signer3.clone()]),
+        &None,
+        &None,
+    );
+
+    // Signers added later cannot sign a request proposed under the old rules
+    let result = client.approve_request(&request_id, &signer3);
+    assert!(!result.success);
+
+    let result = client.approve_request(&request_id, &signer2);
+    assert_eq!(
+        result.final_status,
+        OptionalRequestStatus::Some(RequestStatus::Approved)
+    );
+    assert_eq!(client.get_pending_request(&request_id).approvals.len(), 2);
+}
+
+#[test]
+fn test_signer_inboxes_follow_rotated_signers() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let signer3 = Address::generate(&env);
+    let page = Pagination {
+        cursor: 0,
+        limit: 10,
+    };
+
+    env.mock_all_auths();
+    client.init_multisig_config(
+        &issuer,
+        &2,
+        &vec![&env, signer1.clone(), signer2.clone()],
+        &5,
+        &None,
+        &admin,
+    );
+
+    let request_id = String::from_str(&env, "req-rotated");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+
+    client.update_multisig_config(
+        &issuer,
+        &None,
+        &Some(vec![&env, signer1.clone(), signer3.clone()]),
+        &None,
+        &None,
+    );
+
+    // Under the current rules the added signer sees the request and the removed one does not
+    let inbox = client.get_pending_requests_for_signer(&signer3, &None, &page);
+    assert_eq!(inbox.data.len(), 1);
+    assert_eq!(inbox.data.get(0).unwrap().id, request_id);
+    assert_eq!(
+        client
+            .get_pending_requests_for_signer(&signer2, &None, &page)
+            .data
+            .len(),
+        0
+    );
+
+    // Closing the request clears the inboxes it was re-routed to
+    client.cancel_request(&request_id, &issuer);
+    env.as_contract(&contract_id, || {
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&DataKey::RequestConfigSnapshot(request_id.clone())));
+        // Only open requests are revisited when the signers change again
+        let open: Option<Vec<String>> = env
+            .storage()
+            .instance()
+            .get(&DataKey::IssuerOpenRequestIds(issuer.clone()));
+        assert_eq!(open.map(|ids| ids.len()), Some(0));
+    });
+    assert_eq!(
+        client
+            .get_pending_requests_for_signer(&signer3, &None, &page)
+            .data
+            .len(),
+        0
+    );
+    assert_eq!(
+        client
+            .get_pending_requests_for_signer(&signer1, &None, &page)
+            .data
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_certificate_contract_signer_inboxes_follow_rotated_signers() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let signer3 = Address::generate(&env);
+    let page = Pagination {
+        cursor: 0,
+        limit: 10,
+    };
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.init_multisig_config(
+        &issuer,
+        &2,
+        &vec![&env, signer1.clone(), signer2.clone()],
+        &5,
+        &None,
+        &admin,
+    );
+
+    let request_id = String::from_str(&env, "req-rotated");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+
+    client.update_multisig_config(
+        &issuer,
+        &None,
+        &Some(vec![&env, signer1.clone(), signer3.clone()]),
+        &None,
+        &None,
+    );
+
+    // Under the current rules the added signer sees the request and the removed one does not
+    let inbox = client.get_pending_requests_for_signer(&signer3, &None, &page);
+    assert_eq!(inbox.data.len(), 1);
+    assert_eq!(inbox.data.get(0).unwrap().id, request_id);
+    assert_eq!(
+        client
+            .get_pending_requests_for_signer(&signer2, &None, &page)
+            .data
+            .len(),
+        0
+    );
+
+    // Closing the request clears the inboxes it was re-routed to
+    client.cancel_request(&request_id, &issuer);
+    env.as_contract(&contract_id, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::RequestConfigSnapshot(request_id.clone())));
+        // Only open requests are revisited when the signers change again
+        let open: Option<Vec<String>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IssuerOpenRequestIds(issuer.clone()));
+        assert_eq!(open.map(|ids| ids.len()), Some(0));
+    });
+    assert_eq!(
+        client
+            .get_pending_requests_for_signer(&signer3, &None, &page)
+            .data
+            .len(),
+        0
+    );
+    assert_eq!(
+        client
+            .get_pending_requests_for_signer(&signer1, &None, &page)
+            .data
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_request_lifecycle_emits_events() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+
+    let signers = vec![&env, signer1.clone(), signer2.clone()];
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
+
+    let certificate_contract_id = env.register_contract(None, CertificateContract);
+    let certificate_client = CertificateContractClient::new(&env, &certificate_contract_id);
+    certificate_client.initialize(&admin);
+    certificate_client.add_issuer(&issuer);
+    client.set_certificate_contract(&admin, &certificate_contract_id);
+
+    let request_id = String::from_str(&env, "req-events");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    let last_event = env.events().all().last().unwrap();
+    assert_eq!(last_event.0, client.address);
+    assert_eq!(
+        last_event.1,
+        (
+            symbol_short!("request"),
+            symbol_short!("proposed"),
+            issuer.clone()
+        )
+            .into_val(&env)
+    );
+    let event: RequestProposedEvent = last_event.2.into_val(&env);
+    assert_eq!(event.signers, signers);
+    assert_eq!(event.threshold, 2);
+
+    client.approve_request(&request_id, &signer1);
+    let last_event = env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        (
+            symbol_short!("request"),
+            symbol_short!("approved"),
+            issuer.clone()
+        )
+            .into_val(&env)
+    );
+    let event: RequestApprovedEvent = last_event.2.into_val(&env);
+    assert_eq!(event.approver, signer1);
+    assert_eq!(event.approval_count, 1);
+    assert_eq!(event.threshold, 2);
+    assert_eq!(event.status, RequestStatus::Pending);
+
+    client.approve_request(&request_id, &signer2);
+    let event: RequestApprovedEvent = env.events().all().last().unwrap().2.into_val(&env);
+    assert_eq!(event.approval_count, 2);
+    assert_eq!(event.approval_weight, 2);
+    assert_eq!(event.status, RequestStatus::Approved);
+
+    client.issue_approved_certificate(&request_id);
+    let last_event = env
+        .events()
+        .all()
+        .iter()
+        .filter(|event| event.0 == client.address)
+        .last()
+        .unwrap();
+    assert_eq!(
+        last_event.1,
+        (
+            symbol_short!("request"),
+            symbol_short!("issued"),
+            issuer.clone()
+        )
+            .into_val(&env)
+    );
+    let event: RequestIssuedEvent = last_event.2.into_val(&env);
+    assert_eq!(
+        event,
+        RequestIssuedEvent {
+            request_id,
+            issuer,
+            recipient,
+        }
+    );
+}
+
+#[test]
+fn test_reject_and_cancel_emit_request_events() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &1, &vec![&env, signer1.clone()], &5, &None, &admin);
+
+    let metadata = String::from_str(&env, "certificate metadata");
+    let rejected_id = String::from_str(&env, "req-rejected");
+    client.propose_certificate(
+        &rejected_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    let reason = String::from_str(&env, "wrong programme");
+    client.reject_request(&rejected_id, &signer1, &Some(reason.clone()));
+    let last_event = env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        (
+            symbol_short!("request"),
+            symbol_short!("rejected"),
+            issuer.clone()
+        )
+            .into_val(&env)
+    );
+    let event: RequestRejectedEvent = last_event.2.into_val(&env);
+    assert_eq!(
+        event,
+        RequestRejectedEvent {
+            request_id: rejected_id,
+            rejector: signer1,
+            reason: Some(reason),
+            status: RequestStatus::Rejected,
+        }
+    );
+
+    let cancelled_id = String::from_str(&env, "req-cancelled");
+    client.propose_certificate(
+        &cancelled_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &metadata,
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    client.cancel_request(&cancelled_id, &issuer);
+    let last_event = env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        (
+            symbol_short!("request"),
+            symbol_short!("cancelled"),
+            issuer.clone()
+        )
+            .into_val(&env)
+    );
+    let event: RequestCancelledEvent = last_event.2.into_val(&env);
+    assert_eq!(
+        event,
+        RequestCancelledEvent {
+            request_id: cancelled_id,
+            proposer: issuer,
+        }
+    );
+}
+
+#[test]
+fn test_issued_certificate_uses_requested_validity() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &1, &vec![&env, signer1.clone()], &5, &None, &admin);
+
+    let certificate_contract_id = env.register_contract(None, CertificateContract);
+    let certificate_client = CertificateContractClient::new(&env, &certificate_contract_id);
+    certificate_client.initialize(&admin);
+    certificate_client.add_issuer(&issuer);
+    client.set_certificate_contract(&admin, &certificate_contract_id);
+
+    let four_years = 4 * 365 * 24 * 60 * 60;
+    let schema = String::from_str(&env, "schema:degree:v2");
+    let request_id = String::from_str(&env, "req-validity");
+    let request = client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Duration(four_years),
+        &TransferPolicy::FreelyTransferable,
+        &Some(schema.clone()),
+    );
+    assert_eq!(request.validity, CertificateValidity::Duration(four_years));
+
+    client.approve_request(&request_id, &signer1);
+    env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+    assert!(client.issue_approved_certificate(&request_id));
+
+    // The certificate outlives the request's approval deadline
+    let certificate = certificate_client.get_certificate(&request_id).unwrap();
+    assert_eq!(
+        certificate.expires_at,
+        Some(env.ledger().timestamp() + four_years)
+    );
+    assert_eq!(certificate.metadata_schema, Some(schema));
+}
+
+#[test]
+#[should_panic(expected = "Certificate expiry must be in the future")]
+fn test_propose_rejects_past_certificate_expiry() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.init_multisig_config(&issuer, &1, &vec![&env, signer1], &5, &None, &admin);
+
+    client.propose_certificate(
+        &String::from_str(&env, "req-past"),
+        &issuer,
+        &issuer,
+        &Address::generate(&env),
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::ExpiresAt(500),
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+}
+
+#[test]
+fn test_request_window_counted_in_ledgers() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let signers = vec![&env, signer1.clone(), signer2.clone()];
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
+
+    let request_id = String::from_str(&env, "req-ledger-window");
+    let request = client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::Ledger(10),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    assert_eq!(
+        request.expires_at,
+        Deadline::Ledger(env.ledger().sequence() + 10)
+    );
+
+    // Wall-clock time alone does not expire a ledger-bounded request
+    env.ledger()
+        .with_mut(|li| li.timestamp += 30 * 24 * 60 * 60);
+    assert!(!client.is_expired(&request_id));
+
+    env.ledger().with_mut(|li| li.sequence_number += 11);
+    assert!(client.is_expired(&request_id));
+    let result = client.approve_request(&request_id, &signer1);
+    assert!(!result.success);
+}
+
+#[test]
+fn test_deadline_conversions() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 100;
+        li.timestamp = 10_000;
+    });
+
+    assert_eq!(Deadline::days(1), Deadline::Timestamp(24 * 60 * 60));
+    assert_eq!(Deadline::Ledger(20).from_now(&env), Deadline::Ledger(120));
+    assert_eq!(
+        Deadline::Timestamp(60).from_now(&env),
+        Deadline::Timestamp(10_060)
+    );
+
+    assert_eq!(Deadline::Ledger(120).to_timestamp(&env), 10_100);
+    assert_eq!(Deadline::Ledger(90).to_timestamp(&env), 9_950);
+    assert_eq!(Deadline::Timestamp(10_100).to_ledger(&env), 120);
+    assert_eq!(Deadline::Timestamp(10_003).to_ledger(&env), 101);
+    assert_eq!(Deadline::Timestamp(9_950).to_ledger(&env), 90);
+
+    let deadline = Deadline::Timestamp(10_050);
+    assert!(!deadline.has_passed(&env));
+    env.ledger().with_mut(|li| li.timestamp = 10_051);
+    assert!(deadline.has_passed(&env));
+}
+
+#[test]
+fn test_multisig_issuance_keeps_requested_transfer_policy() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultisigCertificateContract);
+    let client = MultisigCertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_multisig_config(&issuer, &1, &vec![&env, signer1.clone()], &5, &None, &admin);
+
+    let certificate_contract_id = env.register_contract(None, CertificateContract);
+    let certificate_client = CertificateContractClient::new(&env, &certificate_contract_id);
+    certificate_client.initialize(&admin);
+    certificate_client.add_issuer(&issuer);
+    client.set_certificate_contract(&admin, &certificate_contract_id);
+
+    let request_id = String::from_str(&env, "req-soulbound");
+    let request = client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::NonTransferable,
+        &None,
+    );
+    assert_eq!(request.transfer_policy, TransferPolicy::NonTransferable);
+
+    client.approve_request(&request_id, &signer1);
+    assert!(client.issue_approved_certificate(&request_id));
+
+    let certificate = certificate_client.get_certificate(&request_id).unwrap();
+    assert_eq!(certificate.transfer_policy, TransferPolicy::NonTransferable);
+}
+
+#[test]
+fn test_certificate_contract_rejections_require_distinct_signers() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let signer3 = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let signers = vec![&env, signer1.clone(), signer2.clone(), signer3.clone()];
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
+
+    let request_id = String::from_str(&env, "req-reject-guard");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+
+    let result = client.reject_request(
+        &request_id,
+        &outsider,
+        &Some(String::from_str(&env, "spoofed reason")),
+    );
+    assert!(!result.success);
+    let request = client.get_pending_request(&request_id, &issuer);
+    assert_eq!(request.rejections.len(), 0);
+    assert_eq!(request.rejection_reason, None);
+
+    let reason = String::from_str(&env, "wrong recipient");
+    assert!(
+        client
+            .reject_request(&request_id, &signer1, &Some(reason.clone()))
+            .success
+    );
+    let result = client.reject_request(
+        &request_id,
+        &signer1,
+        &Some(String::from_str(&env, "overwritten")),
+    );
+    assert!(!result.success);
+
+    let request = client.get_pending_request(&request_id, &issuer);
+    assert_eq!(request.rejections.len(), 1);
+    assert_eq!(request.rejection_reason, Some(reason));
+    assert_eq!(request.status, RequestStatus::Pending);
+}
+
+#[test]
+fn test_certificate_contract_non_signer_rejection_publishes_no_event() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.init_multisig_config(
+        &issuer,
+        &2,
+        &vec![&env, signer1.clone(), signer2.clone()],
+        &5,
+        &None,
+        &admin,
+    );
+
+    let request_id = String::from_str(&env, "req-outsider");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+
+    let rejected_topics = (
+        symbol_short!("request"),
+        symbol_short!("rejected"),
+        issuer.clone(),
+    )
+        .into_val(&env);
+    let result = client.reject_request(&request_id, &outsider, &None);
+    assert!(!result.success);
+    assert_eq!(
+        env.events()
+            .all()
+            .iter()
+            .filter(|event| event.1 == rejected_topics)
+            .count(),
+        0
+    );
+    assert_eq!(
+        client.get_pending_request(&request_id, &issuer).status,
+        RequestStatus::Pending
+    );
+
+    client.reject_request(&request_id, &signer1, &None);
+    assert_eq!(
+        env.events()
+            .all()
+            .iter()
+            .filter(|event| event.1 == rejected_topics)
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_certificate_contract_issues_legacy_request() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let signers = vec![&env, signer1.clone(), signer2.clone()];
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.add_issuer(&issuer);
+    client.init_multisig_config(&issuer, &2, &signers, &5, &None, &admin);
+
+    let request_id = String::from_str(&env, "req-legacy");
+    client.propose_certificate(
+        &request_id,
+        &issuer,
+        &issuer,
+        &recipient,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+
+    // Rewrite the request in the shape stored before the upgrade
+    let expires_at = env.ledger().timestamp() + 7 * 24 * 60 * 60;
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(
+            &DataKey::PendingRequest(request_id.clone()),
+            &LegacyPendingRequest {
+                id: request_id.clone(),
+                issuer: issuer.clone(),
+                recipient: recipient.clone(),
+                metadata: String::from_str(&env, "certificate metadata"),
+                proposer: issuer.clone(),
+                approvals: Vec::new(&env),
+                rejections: Vec::new(&env),
+                rejection_reason: None,
+                created_at: env.ledger().timestamp(),
+                expires_at,
+                status: RequestStatus::Pending,
+            },
+        );
+    });
+
+    let request = client.get_pending_request(&request_id, &issuer);
+    assert_eq!(request.expires_at, Deadline::Timestamp(expires_at));
+    assert_eq!(request.certificate_type, String::from_str(&env, ""));
+    assert_eq!(request.transfer_policy, TransferPolicy::FreelyTransferable);
+
+    client.approve_request(&request_id, &signer1);
+    client.approve_request(&request_id, &signer2);
+    assert!(client.issue_approved_certificate(&request_id));
+
+    // Pre-upgrade requests bounded the certificate by their own expiry
+    let cert = client.get_certificate(&request_id).unwrap();
+    assert_eq!(cert.owner, recipient);
+    assert_eq!(cert.expires_at, Some(expires_at));
+    assert_eq!(
+        client.get_pending_request(&request_id, &issuer).status,
+        RequestStatus::Issued
+    );
+}
@@ -0,0 +1,201 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+struct PauseSetup<'a> {
+    client: CertificateContractClient<'a>,
+    admin: Address,
+    issuer: Address,
+    owner: Address,
+}
+
+fn setup(env: &Env) -> PauseSetup<'_> {
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let issuer = Address::generate(env);
+    let owner = Address::generate(env);
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.add_issuer(&issuer);
+
+    PauseSetup {
+        client,
+        admin,
+        issuer,
+        owner,
+    }
+}
+
+fn issue(env: &Env, setup: &PauseSetup<'_>, id: &str) -> String {
+    let cert_id = String::from_str(env, id);
+    setup.client.issue_certificate(
+        &cert_id,
+        &setup.issuer,
+        &setup.owner,
+        &String::from_str(env, "ipfs://meta"),
+        &None,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+        &String::from_str(env, "diploma"),
+    );
+    cert_id
+}
+
+#[test]
+fn test_global_pause_halts_issuance_and_transfers() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let client = &setup.client;
+    let cert_id = issue(&env, &setup, "cert-1");
+    let new_owner = Address::generate(&env);
+    let transfer_id = String::from_str(&env, "transfer-1");
+
+    client.initiate_transfer(
+        &transfer_id,
+        &cert_id,
+        &setup.owner,
+        &new_owner,
+        &false,
+        &0,
+        &None,
+    );
+    client.accept_transfer(&transfer_id, &new_owner);
+
+    client.set_paused(&setup.admin, &true);
+    assert!(client.is_paused());
+
+    assert!(client
+        .try_issue_certificate(
+            &String::from_str(&env, "cert-2"),
+            &setup.issuer,
+            &setup.owner,
+            &String::from_str(&env, "ipfs://meta"),
+            &None,
+            &TransferPolicy::FreelyTransferable,
+            &None,
+            &String::from_str(&env, "diploma"),
+        )
+        .is_err());
+    assert!(client
+        .try_reissue_certificate(
+            &cert_id,
+            &String::from_str(&env, "cert-1-v2"),
+            &setup.issuer,
+            &None,
+            &String::from_str(&env, "ipfs://meta-v2"),
+            &None,
+        )
+        .is_err());
+    assert!(client
+        .try_initiate_transfer(
+            &String::from_str(&env, "transfer-2"),
+            &cert_id,
+            &setup.owner,
+            &new_owner,
+            &false,
+            &0,
+            &None,
+        )
+        .is_err());
+    assert!(client
+        .try_complete_transfer(&transfer_id, &setup.owner)
+        .is_err());
+
+    // Verification keeps working while paused
+    assert!(client.is_valid(&cert_id));
+    let report = client.batch_verify_certificates(&vec![&env, cert_id.clone()]);
+    assert_eq!(report.successful, 1);
+
+    client.set_paused(&setup.admin, &false);
+    client.complete_transfer(&transfer_id, &setup.owner);
+    assert_eq!(client.get_certificate(&cert_id).unwrap().owner, new_owner);
+}
+
+#[test]
+fn test_guardian_pauses_single_issuer() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let client = &setup.client;
+    let guardian = Address::generate(&env);
+    let other_issuer = Address::generate(&env);
+    client.add_issuer(&other_issuer);
+    client.set_guardian(&Some(guardian.clone()));
+    assert_eq!(client.get_guardian(), Some(guardian.clone()));
+
+    client.set_issuer_paused(&guardian, &setup.issuer, &true);
+    assert!(client.is_issuer_paused(&setup.issuer));
+    assert!(!client.is_paused());
+
+    let result = client.try_issue_certificate(
+        &String::from_str(&env, "cert-paused"),
+        &setup.issuer,
+        &setup.owner,
+        &String::from_str(&env, "ipfs://meta"),
+        &None,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+        &String::from_str(&env, "diploma"),
+    );
+    assert!(result.is_err());
+
+    // Other issuers are unaffected
+    client.issue_certificate(
+        &String::from_str(&env, "cert-other"),
+        &other_issuer,
+        &setup.owner,
+        &String::from_str(&env, "ipfs://meta"),
+        &None,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+        &String::from_str(&env, "diploma"),
+    );
+
+    client.set_issuer_paused(&setup.admin, &setup.issuer, &false);
+    issue(&env, &setup, "cert-resumed");
+}
+
+#[test]
+#[should_panic(expected = "Only admin or guardian can pause")]
+fn test_outsider_cannot_pause() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let outsider = Address::generate(&env);
+
+    setup.client.set_paused(&outsider, &true);
+}
+
+#[test]
+fn test_pause_blocks_multisig_issuance() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let client = &setup.client;
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let signers = vec![&env, signer1.clone(), signer2.clone()];
+    client.init_multisig_config(&setup.issuer, &1, &signers, &5, &None, &setup.admin);
+
+    let request_id = String::from_str(&env, "req-paused");
+    client.propose_certificate(
+        &request_id,
+        &setup.issuer,
+        &setup.issuer,
+        &setup.owner,
+        &String::from_str(&env, "certificate metadata"),
+        &Deadline::days(7),
+        &String::from_str(&env, "diploma"),
+        &CertificateValidity::Permanent,
+        &TransferPolicy::FreelyTransferable,
+        &None,
+    );
+    client.approve_request(&request_id, &signer1);
+
+    client.set_issuer_paused(&setup.admin, &setup.issuer, &true);
+    assert!(client.try_issue_approved_certificate(&request_id).is_err());
+
+    client.set_issuer_paused(&setup.admin, &setup.issuer, &false);
+    assert!(client.issue_approved_certificate(&request_id));
+}
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::{Address as _, Events}, Address, Env, String, symbol_short, IntoVal};
+use soroban_sdk::{testutils::{Address as _, Events, Ledger}, Address, Env, String, symbol_short, vec, IntoVal};
 
 #[test]
 fn test_status_transition_events() {
@@ -20,7 +20,7 @@ fn test_status_transition_events() {
     client.add_issuer(&issuer);
 
     // Issue certificate
-    client.issue_certificate(&cert_id, &issuer, &owner, &metadata_uri, &None);
+    client.issue_certificate(&cert_id, &issuer, &owner, &metadata_uri, &None, &TransferPolicy::FreelyTransferable, &None, &String::from_str(&env, "diploma"));
 
     // Test suspend
     client.suspend_certificate(&cert_id, &String::from_str(&env, "suspended for testing"));
@@ -47,3 +47,139 @@ fn test_status_transition_events() {
     let last_event = events.last().unwrap();
     assert_eq!(last_event.1, (symbol_short!("unfrozen"), cert_id.clone()).into_val(&env));
 }
+
+#[test]
+fn test_certificates_by_issuer_filtered_by_status() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let metadata_uri = String::from_str(&env, "ipfs://test");
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.add_issuer(&issuer);
+
+    for id in ["cert-1", "cert-2", "cert-3"] {
+        client.issue_certificate(&String::from_str(&env, id), &issuer, &owner, &metadata_uri, &None, &TransferPolicy::FreelyTransferable, &None, &String::from_str(&env, "diploma"));
+    }
+    client.suspend_certificate(&String::from_str(&env, "cert-2"), &String::from_str(&env, "under review"));
+
+    let first_page = client.get_certificates_by_issuer(&issuer, &None, &Pagination { cursor: 0, limit: 2 });
+    assert_eq!(first_page.data.len(), 2);
+    assert_eq!(first_page.next_cursor, Some(2));
+
+    let second_page = client.get_certificates_by_issuer(&issuer, &None, &Pagination { cursor: 2, limit: 2 });
+    assert_eq!(second_page.data.len(), 1);
+    assert_eq!(second_page.next_cursor, None);
+
+    let suspended = client.get_certificates_by_owner(&owner, &Some(CertificateStatus::Suspended), &Pagination { cursor: 0, limit: 10 });
+    assert_eq!(suspended.data.len(), 1);
+    assert_eq!(suspended.data.get(0).unwrap().id, String::from_str(&env, "cert-2"));
+}
+
+#[test]
+fn test_filtered_page_stops_after_scan_limit() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let metadata_uri = String::from_str(&env, "ipfs://test");
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.add_issuer(&issuer);
+
+    let total = Pagination::MAX_SCAN + 5;
+    for i in 0..total {
+        let id = String::from_bytes(&env, &[b'c', b'0' + (i / 10) as u8, b'0' + (i % 10) as u8]);
+        client.issue_certificate(&id, &issuer, &owner, &metadata_uri, &None, &TransferPolicy::FreelyTransferable, &None, &String::from_str(&env, "diploma"));
+    }
+    let last_id = String::from_bytes(&env, &[b'c', b'0' + ((total - 1) / 10) as u8, b'0' + ((total - 1) % 10) as u8]);
+    client.suspend_certificate(&last_id, &String::from_str(&env, "under review"));
+
+    // No match within the scan limit: an empty page that still points further on
+    let suspended = Some(CertificateStatus::Suspended);
+    let first_page = client.get_certificates_by_issuer(&issuer, &suspended, &Pagination { cursor: 0, limit: 10 });
+    assert_eq!(first_page.data.len(), 0);
+    assert_eq!(first_page.next_cursor, Some(Pagination::MAX_SCAN));
+
+    let second_page = client.get_certificates_by_issuer(&issuer, &suspended, &Pagination { cursor: first_page.next_cursor.unwrap(), limit: 10 });
+    assert_eq!(second_page.data.len(), 1);
+    assert_eq!(second_page.data.get(0).unwrap().id, last_id);
+    assert_eq!(second_page.next_cursor, None);
+}
+
+#[test]
+fn test_retired_issuer_keeps_past_certificates_valid() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let cert_id = String::from_str(&env, "cert-retired");
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.add_issuer(&issuer);
+    client.issue_certificate(&cert_id, &issuer, &owner, &String::from_str(&env, "ipfs://meta"), &None, &TransferPolicy::FreelyTransferable, &None, &String::from_str(&env, "diploma"));
+
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.remove_issuer(&issuer);
+
+    let record = client.get_issuer_status(&issuer);
+    assert_eq!(record.status, IssuerStatus::Retired);
+    assert_eq!(record.effective_from, env.ledger().timestamp());
+    assert!(client.is_valid(&cert_id));
+    assert_eq!(client.batch_verify_certificates(&vec![&env, cert_id.clone()]).successful, 1);
+
+    // Coming back clears the retirement
+    client.add_issuer(&issuer);
+    assert_eq!(client.get_issuer_status(&issuer).status, IssuerStatus::Active);
+}
+
+#[test]
+fn test_compromised_issuer_invalidates_certificates_from_effective_time() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let cert_id = String::from_str(&env, "cert-compromised");
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.add_issuer(&issuer);
+    client.issue_certificate(&cert_id, &issuer, &owner, &String::from_str(&env, "ipfs://meta"), &None, &TransferPolicy::FreelyTransferable, &None, &String::from_str(&env, "diploma"));
+
+    let compromised_at = env.ledger().timestamp() + 500;
+    client.set_issuer_status(&issuer, &IssuerStatus::Compromised, &compromised_at);
+    assert!(client.is_valid(&cert_id));
+
+    env.ledger().with_mut(|li| li.timestamp = compromised_at);
+    assert!(!client.is_valid(&cert_id));
+    let report = client.batch_verify_certificates(&vec![&env, cert_id.clone()]);
+    assert_eq!(report.failed, 1);
+    assert!(report.results.get(0).unwrap().revoked);
+
+    // The certificate record itself is untouched
+    assert_eq!(client.get_certificate(&cert_id).unwrap().status, CertificateStatus::Active);
+
+    let result = client.try_issue_certificate(&String::from_str(&env, "cert-after"), &issuer, &owner, &String::from_str(&env, "ipfs://meta"), &None, &TransferPolicy::FreelyTransferable, &None, &String::from_str(&env, "diploma"));
+    assert!(result.is_err());
+
+    // Re-adding does not clear a compromise
+    client.remove_issuer(&issuer);
+    client.add_issuer(&issuer);
+    assert_eq!(client.get_issuer_status(&issuer).status, IssuerStatus::Compromised);
+}
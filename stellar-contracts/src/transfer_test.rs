@@ -0,0 +1,462 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, String,
+};
+
+fn setup_certificate(
+    env: &Env,
+    policy: TransferPolicy,
+) -> (CertificateContractClient<'_>, Address, Address, String) {
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let issuer = Address::generate(env);
+    let owner = Address::generate(env);
+    let cert_id = String::from_str(env, "cert-transfer");
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.add_issuer(&issuer);
+    client.issue_certificate(
+        &cert_id,
+        &issuer,
+        &owner,
+        &String::from_str(env, "ipfs://meta"),
+        &None,
+        &policy,
+        &None,
+        &String::from_str(env, "diploma"),
+    );
+
+    (client, issuer, owner, cert_id)
+}
+
+#[test]
+fn test_freely_transferable_certificate_changes_owner() {
+    let env = Env::default();
+    let (client, _issuer, owner, cert_id) =
+        setup_certificate(&env, TransferPolicy::FreelyTransferable);
+    let new_owner = Address::generate(&env);
+    let transfer_id = String::from_str(&env, "transfer-1");
+
+    client.initiate_transfer(&transfer_id, &cert_id, &owner, &new_owner, &false, &0, &None);
+    client.accept_transfer(&transfer_id, &new_owner);
+    client.complete_transfer(&transfer_id, &owner);
+
+    assert_eq!(client.get_certificate(&cert_id).unwrap().owner, new_owner);
+    assert_eq!(
+        client.get_transfer(&transfer_id).status,
+        TransferStatus::Completed
+    );
+}
+
+#[test]
+#[should_panic(expected = "Certificate is non-transferable")]
+fn test_non_transferable_certificate_cannot_be_transferred() {
+    let env = Env::default();
+    let (client, _issuer, owner, cert_id) =
+        setup_certificate(&env, TransferPolicy::NonTransferable);
+    let new_owner = Address::generate(&env);
+
+    client.initiate_transfer(
+        &String::from_str(&env, "transfer-1"),
+        &cert_id,
+        &owner,
+        &new_owner,
+        &false,
+        &0,
+        &None,
+    );
+}
+
+#[test]
+fn test_issuer_approval_required_before_completion() {
+    let env = Env::default();
+    let (client, issuer, owner, cert_id) =
+        setup_certificate(&env, TransferPolicy::IssuerApprovalRequired);
+    let new_owner = Address::generate(&env);
+    let transfer_id = String::from_str(&env, "transfer-1");
+
+    client.initiate_transfer(&transfer_id, &cert_id, &owner, &new_owner, &false, &0, &None);
+
+    // Issuer cannot approve before the recipient has accepted
+    assert!(client
+        .try_approve_transfer_as_issuer(&transfer_id, &issuer)
+        .is_err());
+
+    client.accept_transfer(&transfer_id, &new_owner);
+
+    // Completion is blocked until the issuer signs off
+    assert!(client.try_complete_transfer(&transfer_id, &owner).is_err());
+
+    client.approve_transfer_as_issuer(&transfer_id, &issuer);
+    let transfer = client.get_transfer(&transfer_id);
+    assert_eq!(transfer.status, TransferStatus::IssuerApproved);
+    assert!(transfer.issuer_approved_at.is_some());
+
+    client.complete_transfer(&transfer_id, &owner);
+    assert_eq!(client.get_certificate(&cert_id).unwrap().owner, new_owner);
+}
+
+#[test]
+#[should_panic(expected = "Only certificate issuer can approve transfer")]
+fn test_only_issuer_can_approve_transfer() {
+    let env = Env::default();
+    let (client, _issuer, owner, cert_id) =
+        setup_certificate(&env, TransferPolicy::IssuerApprovalRequired);
+    let new_owner = Address::generate(&env);
+    let transfer_id = String::from_str(&env, "transfer-1");
+
+    client.initiate_transfer(&transfer_id, &cert_id, &owner, &new_owner, &false, &0, &None);
+    client.accept_transfer(&transfer_id, &new_owner);
+    client.approve_transfer_as_issuer(&transfer_id, &new_owner);
+}
+
+#[test]
+fn test_outgoing_and_incoming_transfer_queries() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CertificateContract);
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.add_issuer(&issuer);
+
+    for (cert, transfer) in [("cert-1", "tr-1"), ("cert-2", "tr-2"), ("cert-3", "tr-3")] {
+        let cert_id = String::from_str(&env, cert);
+        client.issue_certificate(
+            &cert_id,
+            &issuer,
+            &sender,
+            &String::from_str(&env, "ipfs://meta"),
+            &None,
+            &TransferPolicy::FreelyTransferable,
+            &None,
+            &String::from_str(&env, "diploma"),
+        );
+        client.initiate_transfer(
+            &String::from_str(&env, transfer),
+            &cert_id,
+            &sender,
+            &recipient,
+            &false,
+            &0,
+            &None,
+        );
+    }
+    client.cancel_transfer(&String::from_str(&env, "tr-2"), &sender);
+
+    let first_page = client.get_outgoing_transfers(
+        &sender,
+        &None,
+        &Pagination {
+            cursor: 0,
+            limit: 2,
+        },
+    );
+    assert_eq!(first_page.data.len(), 2);
+    assert_eq!(first_page.next_cursor, Some(2));
+    assert_eq!(
+        first_page.data.get(0).unwrap().id,
+        String::from_str(&env, "tr-1")
+    );
+
+    let second_page = client.get_outgoing_transfers(
+        &sender,
+        &None,
+        &Pagination {
+            cursor: 2,
+            limit: 2,
+        },
+    );
+    assert_eq!(second_page.data.len(), 1);
+    assert_eq!(second_page.next_cursor, None);
+
+    let pending = client.get_incoming_transfers(
+        &recipient,
+        &Some(TransferStatus::Pending),
+        &Pagination {
+            cursor: 0,
+            limit: 10,
+        },
+    );
+    assert_eq!(pending.data.len(), 2);
+    assert!(pending
+        .data
+        .iter()
+        .all(|transfer| transfer.status == TransferStatus::Pending));
+
+    let cancelled = client.get_certificate_transfers(
+        &String::from_str(&env, "cert-2"),
+        &Some(TransferStatus::Cancelled),
+        &Pagination {
+            cursor: 0,
+            limit: 10,
+        },
+    );
+    assert_eq!(cancelled.data.len(), 1);
+    assert_eq!(cancelled.data.get(0).unwrap().to_owner, recipient);
+
+    // The recipient has not initiated anything
+    let none = client.get_outgoing_transfers(
+        &recipient,
+        &None,
+        &Pagination {
+            cursor: 0,
+            limit: 10,
+        },
+    );
+    assert_eq!(none.data.len(), 0);
+}
+
+#[test]
+fn test_transfer_state_changes_emit_events() {
+    let env = Env::default();
+    let (client, issuer, owner, cert_id) =
+        setup_certificate(&env, TransferPolicy::IssuerApprovalRequired);
+    let new_owner = Address::generate(&env);
+    let transfer_id = String::from_str(&env, "transfer-1");
+
+    client.initiate_transfer(&transfer_id, &cert_id, &owner, &new_owner, &false, &0, &None);
+    let last_event = env.events().all().last().unwrap();
+    assert_eq!(last_event.0, client.address);
+    assert_eq!(
+        last_event.1,
+        (
+            symbol_short!("xfer_init"),
+            cert_id.clone(),
+            owner.clone(),
+            new_owner.clone()
+        )
+            .into_val(&env)
+    );
+
+    client.accept_transfer(&transfer_id, &new_owner);
+    let last_event = env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        (
+            symbol_short!("xfer_acc"),
+            cert_id.clone(),
+            owner.clone(),
+            new_owner.clone()
+        )
+            .into_val(&env)
+    );
+
+    client.approve_transfer_as_issuer(&transfer_id, &issuer);
+    let last_event = env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        (
+            symbol_short!("xfer_appr"),
+            cert_id.clone(),
+            owner.clone(),
+            new_owner.clone()
+        )
+            .into_val(&env)
+    );
+
+    client.complete_transfer(&transfer_id, &owner);
+    let last_event = env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        (
+            symbol_short!("xfer_done"),
+            cert_id.clone(),
+            owner.clone(),
+            new_owner.clone()
+        )
+            .into_val(&env)
+    );
+    let event: TransferCompletedEvent = last_event.2.into_val(&env);
+    assert_eq!(
+        event,
+        TransferCompletedEvent {
+            transfer_id,
+            certificate_id: cert_id,
+            from_owner: owner,
+            to_owner: new_owner,
+            revoked: false,
+        }
+    );
+}
+
+#[test]
+fn test_reject_and_cancel_emit_events() {
+    let env = Env::default();
+    let (client, _issuer, owner, cert_id) =
+        setup_certificate(&env, TransferPolicy::FreelyTransferable);
+    let new_owner = Address::generate(&env);
+
+    let rejected_id = String::from_str(&env, "transfer-rejected");
+    client.initiate_transfer(&rejected_id, &cert_id, &owner, &new_owner, &false, &0, &None);
+    client.reject_transfer(&rejected_id, &new_owner);
+    let last_event = env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        (
+            symbol_short!("xfer_rej"),
+            cert_id.clone(),
+            owner.clone(),
+            new_owner.clone()
+        )
+            .into_val(&env)
+    );
+
+    let cancelled_id = String::from_str(&env, "transfer-cancelled");
+    client.initiate_transfer(&cancelled_id, &cert_id, &owner, &new_owner, &false, &0, &None);
+    client.cancel_transfer(&cancelled_id, &owner);
+    let last_event = env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        (symbol_short!("xfer_cncl"), cert_id, owner, new_owner).into_val(&env)
+    );
+}
+
+#[test]
+fn test_accepted_transfer_can_be_denied_rejected_or_cancelled() {
+    let env = Env::default();
+    let (client, issuer, owner, cert_id) =
+        setup_certificate(&env, TransferPolicy::IssuerApprovalRequired);
+    let new_owner = Address::generate(&env);
+
+    let start = |id: &str| {
+        let transfer_id = String::from_str(&env, id);
+        client.initiate_transfer(
+            &transfer_id,
+            &cert_id,
+            &owner,
+            &new_owner,
+            &false,
+            &0,
+            &None,
+        );
+        client.accept_transfer(&transfer_id, &new_owner);
+        transfer_id
+    };
+
+    let denied_id = start("transfer-denied");
+    client.deny_transfer_as_issuer(&denied_id, &issuer);
+    assert_eq!(
+        client.get_transfer(&denied_id).status,
+        TransferStatus::Rejected
+    );
+    let last_event = env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        (
+            symbol_short!("xfer_deny"),
+            cert_id.clone(),
+            owner.clone(),
+            new_owner.clone()
+        )
+            .into_val(&env)
+    );
+    assert!(client.try_complete_transfer(&denied_id, &owner).is_err());
+
+    let rejected_id = start("transfer-rejected");
+    client.reject_transfer(&rejected_id, &new_owner);
+    assert_eq!(
+        client.get_transfer(&rejected_id).status,
+        TransferStatus::Rejected
+    );
+
+    let cancelled_id = start("transfer-cancelled");
+    client.cancel_transfer(&cancelled_id, &owner);
+    assert_eq!(
+        client.get_transfer(&cancelled_id).status,
+        TransferStatus::Cancelled
+    );
+
+    // Finished transfers stay final
+    assert!(client.try_cancel_transfer(&denied_id, &owner).is_err());
+}
+
+#[test]
+fn test_legacy_certificate_and_transfer_complete() {
+    let env = Env::default();
+    let (client, issuer, owner, cert_id) = setup_certificate(&env, TransferPolicy::NonTransferable);
+    let new_owner = Address::generate(&env);
+    let transfer_id = String::from_str(&env, "transfer-legacy");
+
+    // Rewrite the certificate and an open transfer in the shapes stored before the upgrade
+    let legacy_cert = LegacyCertificate {
+        id: cert_id.clone(),
+        issuer: issuer.clone(),
+        owner: owner.clone(),
+        status: CertificateStatus::Active,
+        metadata_uri: String::from_str(&env, "ipfs://meta"),
+        issued_at: env.ledger().timestamp(),
+        expires_at: None,
+        version: CertificateVersion {
+            major: 1,
+            minor: 0,
+            patch: 0,
+            build: None,
+        },
+        revocation_reason: None,
+        status_reason: None,
+        parent_certificate_id: None,
+    };
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Certificate(cert_id.clone()), &legacy_cert);
+    });
+
+    // Legacy certificates carry no transfer restriction or type
+    let cert = client.get_certificate(&cert_id).unwrap();
+    assert_eq!(cert.transfer_policy, TransferPolicy::FreelyTransferable);
+    assert_eq!(cert.certificate_type, String::from_str(&env, ""));
+    assert_eq!(cert.issued_by, None);
+    assert!(client.is_valid(&cert_id));
+
+    client.initiate_transfer(
+        &transfer_id,
+        &cert_id,
+        &owner,
+        &new_owner,
+        &false,
+        &0,
+        &None,
+    );
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DataKey::Transfer(transfer_id.clone()),
+            &LegacyCertificateTransfer {
+                id: transfer_id.clone(),
+                certificate_id: cert_id.clone(),
+                from_owner: owner.clone(),
+                to_owner: new_owner.clone(),
+                status: TransferStatus::Pending,
+                initiated_at: env.ledger().timestamp(),
+                accepted_at: None,
+                completed_at: None,
+                require_revocation: false,
+                transfer_fee: 0,
+                memo: None,
+            },
+        );
+    });
+    assert_eq!(client.get_transfer(&transfer_id).issuer_approved_at, None);
+
+    client.accept_transfer(&transfer_id, &new_owner);
+    client.complete_transfer(&transfer_id, &owner);
+
+    assert_eq!(client.get_certificate(&cert_id).unwrap().owner, new_owner);
+    assert_eq!(
+        client.get_transfer(&transfer_id).status,
+        TransferStatus::Completed
+    );
+}
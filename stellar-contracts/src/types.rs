@@ -1,4 +1,10 @@
-use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+use soroban_sdk::{
+    contracttype, Address, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+
+/// Open (pending or approved) multisig requests a proposer may hold per issuer
+/// unless the issuer admin configures a different quota.
+pub const DEFAULT_PROPOSER_QUOTA: u32 = 20;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -10,6 +16,14 @@ pub enum CertificateStatus {
     Frozen,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TransferPolicy {
+    NonTransferable,
+    IssuerApprovalRequired,
+    FreelyTransferable,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CertificateVersion {
@@ -33,6 +47,76 @@ pub struct Certificate {
     pub revocation_reason: Option<String>,
     pub status_reason: Option<String>,
     pub parent_certificate_id: Option<String>,
+    pub transfer_policy: TransferPolicy,
+    pub metadata_schema: Option<String>,
+    /// Delegate key that issued the certificate on behalf of `issuer`, if any
+    pub issued_by: Option<Address>,
+    /// Type the certificate was issued as; delegate and accreditation scopes are checked
+    /// against this value
+    pub certificate_type: String,
+}
+
+/// Certificate shape written before transfer policies, schemas, delegates and certificate
+/// types were recorded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyCertificate {
+    pub id: String,
+    pub issuer: Address,
+    pub owner: Address,
+    pub status: CertificateStatus,
+    pub metadata_uri: String,
+    pub issued_at: u64,
+    pub expires_at: Option<u64>,
+    pub version: CertificateVersion,
+    pub revocation_reason: Option<String>,
+    pub status_reason: Option<String>,
+    pub parent_certificate_id: Option<String>,
+}
+
+impl From<LegacyCertificate> for Certificate {
+    fn from(legacy: LegacyCertificate) -> Self {
+        let certificate_type = String::from_str(legacy.id.env(), "");
+        Certificate {
+            id: legacy.id,
+            issuer: legacy.issuer,
+            owner: legacy.owner,
+            status: legacy.status,
+            metadata_uri: legacy.metadata_uri,
+            issued_at: legacy.issued_at,
+            expires_at: legacy.expires_at,
+            version: legacy.version,
+            revocation_reason: legacy.revocation_reason,
+            status_reason: legacy.status_reason,
+            parent_certificate_id: legacy.parent_certificate_id,
+            transfer_policy: TransferPolicy::FreelyTransferable,
+            metadata_schema: None,
+            issued_by: None,
+            certificate_type,
+        }
+    }
+}
+
+impl Certificate {
+    /// Decode a stored certificate, converting entries written in the legacy shape
+    pub fn from_stored(env: &Env, entry: Val) -> Option<Self> {
+        decode_upgraded::<Self, LegacyCertificate>(env, entry, "certificate_type")
+    }
+}
+
+/// Decode a stored record whose type gained fields after entries were written: an entry
+/// without `added_field` is read in its legacy shape `L` and converted.
+fn decode_upgraded<T, L>(env: &Env, entry: Val, added_field: &str) -> Option<T>
+where
+    T: TryFromVal<Env, Val>,
+    L: TryFromVal<Env, Val> + Into<T>,
+{
+    let fields = Map::<Symbol, Val>::try_from_val(env, &entry).ok()?;
+    if fields.contains_key(Symbol::new(env, added_field)) {
+        T::try_from_val(env, &entry).ok()
+    } else {
+        L::try_from_val(env, &entry).ok().map(Into::into)
+    }
 }
 
 #[contracttype]
@@ -40,13 +124,32 @@ pub struct Certificate {
 pub enum DataKey {
     Admin,
     Issuer(Address),
-    IssuerCount,
+    /// Pre-registry issuer list, moved into `IssuerAt`/`IssuerIndex` by `migrate_issuer_registry`
     Issuers,
+    /// Position in the legacy issuer list reached by an unfinished migration
+    IssuerMigrationCursor,
+    IssuerCount,
+    /// Dense issuer registry: slot index -> issuer, and issuer -> slot index
+    IssuerAt(u32),
+    IssuerIndex(Address),
     Certificate(String),
     MultisigConfig(Address),
+    WeightedMultisigConfig(Address),
+    MultisigPolicy(Address, String),
+    MultisigPolicyTypes(Address),
+    ExecutionDelay(Address),
+    Proposers(Address),
+    ProposerQuota(Address),
+    OpenRequestCount(Address, Address),
+    RequestAmendments(String),
+    MultisigConfigVersion(Address),
+    RequestConfigSnapshot(String),
+    SignerRotationPolicy(Address),
     IssuerAdmin(Address),
     PendingRequest(String),
     IssuerRequestIds(Address),
+    /// Requests of an issuer that are still pending or approved, for re-routing on config changes
+    IssuerOpenRequestIds(Address),
     CertificateContract,
     SignerRequestIds(Address),
     IssuerCertIds(Address),
@@ -56,6 +159,87 @@ pub enum DataKey {
     CertificateTransfers(String),
     PendingTransfers(Address),
     TransferCount,
+    IncomingTransfers(Address),
+    OutgoingTransfers(Address),
+    Paused,
+    IssuerPaused(Address),
+    IssuerStatus(Address),
+    IssuerDelegate(Address, Address),
+    /// Per-issuer delegate registry, laid out like the issuer registry
+    IssuerDelegateCount(Address),
+    IssuerDelegateAt(Address, u32),
+    IssuerDelegateIndex(Address, Address),
+    IssuerAccreditation(Address),
+    Guardian,
+    FeeConfig,
+}
+
+/// On-chain record of an authorized issuer, shown to verifiers next to its credentials
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerProfile {
+    pub address: Address,
+    pub display_name: Option<String>,
+    pub did: Option<String>,
+    pub website: Option<String>,
+    /// Content hash of the logo image, e.g. an IPFS CID
+    pub logo_hash: Option<String>,
+    /// Set by the contract admin only; 0 means not accredited
+    pub accreditation_level: u32,
+    pub added_at: u64,
+    pub updated_at: u64,
+}
+
+/// A key allowed to issue certificates under an organization's issuer identity
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuingDelegate {
+    pub delegate: Address,
+    /// Certificate types the delegate may issue; empty allows any type
+    pub certificate_types: Vec<String>,
+    pub expires_at: Option<u64>,
+    /// Maximum number of certificates the delegate may issue; `None` is unlimited
+    pub quota: Option<u32>,
+    pub issued_count: u32,
+}
+
+/// Longest accreditation chain, counting the root issuer
+pub const MAX_ACCREDITATION_DEPTH: u32 = 8;
+
+/// Link from an intermediate issuer to the issuer that accredited it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerAccreditation {
+    pub parent: Address,
+    /// Certificate types the issuer is accredited for; empty inherits the parent's scope
+    pub scope: Vec<String>,
+    pub accredited_at: u64,
+    pub revoked: bool,
+}
+
+/// Standing of an issuer and its effect on certificates it has issued
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IssuerStatus {
+    Active,
+    /// Stopped issuing; certificates issued before `effective_from` stay valid
+    Retired,
+    /// Keys compromised; every certificate from the issuer is invalid from `effective_from`
+    Compromised,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerStatusRecord {
+    pub status: IssuerStatus,
+    pub effective_from: u64,
+}
+
+/// Fee settings governed by the contract admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    pub min_transfer_fee: u64,
 }
 
 #[contracttype]
@@ -109,6 +293,7 @@ pub struct CertificateUnfrozenEvent {
 pub enum TransferStatus {
     Pending,
     Accepted,
+    IssuerApproved,
     Rejected,
     Completed,
     Cancelled,
@@ -117,6 +302,24 @@ pub enum TransferStatus {
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CertificateTransfer {
+    pub id: String,
+    pub certificate_id: String,
+    pub from_owner: Address,
+    pub to_owner: Address,
+    pub status: TransferStatus,
+    pub initiated_at: u64,
+    pub accepted_at: Option<u64>,
+    pub issuer_approved_at: Option<u64>,
+    pub completed_at: Option<u64>,
+    pub require_revocation: bool,
+    pub transfer_fee: u64,
+    pub memo: Option<String>,
+}
+
+/// Transfer shape written before issuer approval was tracked.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyCertificateTransfer {
     pub id: String,
     pub certificate_id: String,
     pub from_owner: Address,
@@ -130,6 +333,32 @@ pub struct CertificateTransfer {
     pub memo: Option<String>,
 }
 
+impl From<LegacyCertificateTransfer> for CertificateTransfer {
+    fn from(legacy: LegacyCertificateTransfer) -> Self {
+        CertificateTransfer {
+            id: legacy.id,
+            certificate_id: legacy.certificate_id,
+            from_owner: legacy.from_owner,
+            to_owner: legacy.to_owner,
+            status: legacy.status,
+            initiated_at: legacy.initiated_at,
+            accepted_at: legacy.accepted_at,
+            issuer_approved_at: None,
+            completed_at: legacy.completed_at,
+            require_revocation: legacy.require_revocation,
+            transfer_fee: legacy.transfer_fee,
+            memo: legacy.memo,
+        }
+    }
+}
+
+impl CertificateTransfer {
+    /// Decode a stored transfer, converting entries written in the legacy shape
+    pub fn from_stored(env: &Env, entry: Val) -> Option<Self> {
+        decode_upgraded::<Self, LegacyCertificateTransfer>(env, entry, "issuer_approved_at")
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TransferHistoryEntry {
@@ -141,6 +370,74 @@ pub struct TransferHistoryEntry {
     pub memo: Option<String>,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferInitiatedEvent {
+    pub transfer_id: String,
+    pub certificate_id: String,
+    pub from_owner: Address,
+    pub to_owner: Address,
+    pub require_revocation: bool,
+    pub transfer_fee: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferAcceptedEvent {
+    pub transfer_id: String,
+    pub certificate_id: String,
+    pub from_owner: Address,
+    pub to_owner: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferIssuerApprovedEvent {
+    pub transfer_id: String,
+    pub certificate_id: String,
+    pub from_owner: Address,
+    pub to_owner: Address,
+    pub issuer: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferIssuerDeniedEvent {
+    pub transfer_id: String,
+    pub certificate_id: String,
+    pub from_owner: Address,
+    pub to_owner: Address,
+    pub issuer: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferCompletedEvent {
+    pub transfer_id: String,
+    pub certificate_id: String,
+    pub from_owner: Address,
+    pub to_owner: Address,
+    pub revoked: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferRejectedEvent {
+    pub transfer_id: String,
+    pub certificate_id: String,
+    pub from_owner: Address,
+    pub to_owner: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferCancelledEvent {
+    pub transfer_id: String,
+    pub certificate_id: String,
+    pub from_owner: Address,
+    pub to_owner: Address,
+}
+
 // Multisig Types
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -148,6 +445,111 @@ pub struct MultisigConfig {
     pub threshold: u32,
     pub signers: Vec<Address>,
     pub max_signers: u32,
+    pub weights: Map<Address, u32>,
+}
+
+impl MultisigConfig {
+    /// Build a config, giving weight 1 to every signer without an explicit weight
+    pub fn new(
+        threshold: u32,
+        signers: Vec<Address>,
+        max_signers: u32,
+        weights: Option<Map<Address, u32>>,
+    ) -> Self {
+        let mut config = MultisigConfig {
+            threshold,
+            weights: weights.unwrap_or(Map::new(signers.env())),
+            signers,
+            max_signers,
+        };
+        config.fill_default_weights();
+        config
+    }
+
+    /// Replace the signer set, keeping the weights of signers that remain
+    pub fn set_signers(&mut self, signers: Vec<Address>) {
+        let mut weights = Map::new(signers.env());
+        for signer in signers.iter() {
+            weights.set(signer.clone(), self.weights.get(signer).unwrap_or(1));
+        }
+        self.signers = signers;
+        self.weights = weights;
+    }
+
+    /// Replace the signer weights (signers left out get weight 1)
+    pub fn set_weights(&mut self, weights: Map<Address, u32>) {
+        self.weights = weights;
+        self.fill_default_weights();
+    }
+
+    fn fill_default_weights(&mut self) {
+        for signer in self.signers.iter() {
+            if !self.weights.contains_key(signer.clone()) {
+                self.weights.set(signer, 1);
+            }
+        }
+    }
+
+    /// Weight of a signer's vote (0 for addresses that are not signers)
+    pub fn weight_of(&self, signer: &Address) -> u32 {
+        if !self.signers.contains(signer) {
+            return 0;
+        }
+        self.weights.get(signer.clone()).unwrap_or(1)
+    }
+
+    /// Combined weight of the given signers
+    pub fn weight_sum(&self, signers: &Vec<Address>) -> u32 {
+        let mut total: u32 = 0;
+        for signer in signers.iter() {
+            total = total.saturating_add(self.weight_of(&signer));
+        }
+        total
+    }
+
+    /// Combined weight of every configured signer
+    pub fn total_weight(&self) -> u32 {
+        self.weight_sum(&self.signers)
+    }
+
+    /// Check thresholds and weights are consistent with the signer set
+    pub fn is_valid(&self) -> bool {
+        if self.threshold == 0
+            || self.signers.is_empty()
+            || self.signers.len() > self.max_signers
+            || self.threshold > self.total_weight()
+        {
+            return false;
+        }
+        for (signer, weight) in self.weights.iter() {
+            if weight == 0 || !self.signers.contains(&signer) {
+                return false;
+            }
+        }
+        // A repeated signer would count its weight more than once
+        for (index, signer) in self.signers.iter().enumerate() {
+            if self.signers.first_index_of(&signer) != Some(index as u32) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Multisig configuration as stored before signer weights were introduced.
+/// Still readable from `DataKey::MultisigConfig` and migrated with weight 1 per signer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyMultisigConfig {
+    pub threshold: u32,
+    pub signers: Vec<Address>,
+    pub max_signers: u32,
+}
+
+impl From<LegacyMultisigConfig> for MultisigConfig {
+    fn from(legacy: LegacyMultisigConfig) -> Self {
+        MultisigConfig::new(legacy.threshold, legacy.signers, legacy.max_signers, None)
+    }
 }
 
 #[contracttype]
@@ -159,6 +561,14 @@ pub enum RequestStatus {
     Cancelled,
     Expired,
     Issued,
+    Vetoed,
+}
+
+impl RequestStatus {
+    /// Whether the request still counts against its proposer's open-request quota
+    pub fn is_open(&self) -> bool {
+        matches!(self, RequestStatus::Pending | RequestStatus::Approved)
+    }
 }
 
 #[contracttype]
@@ -171,6 +581,32 @@ pub enum OptionalRequestStatus {
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PendingRequest {
+    pub id: String,
+    pub issuer: Address,
+    pub recipient: Address,
+    pub metadata: String,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    pub rejections: Vec<Address>,
+    pub rejection_reason: Option<String>,
+    pub created_at: u64,
+    pub expires_at: Deadline,
+    pub status: RequestStatus,
+    pub certificate_type: String,
+    pub executable_at: OptionalDeadline,
+    pub amendment_count: u32,
+    pub config_version: u32,
+    pub validity: CertificateValidity,
+    /// Transfer policy applied to the certificate once issued
+    pub transfer_policy: TransferPolicy,
+    pub metadata_schema: Option<String>,
+}
+
+/// Request shape written before certificate types, time locks, amendments and config
+/// snapshots existed; its expiry was a timestamp that also bounded the issued certificate.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyPendingRequest {
     pub id: String,
     pub issuer: Address,
     pub recipient: Address,
@@ -184,6 +620,266 @@ pub struct PendingRequest {
     pub status: RequestStatus,
 }
 
+impl From<LegacyPendingRequest> for PendingRequest {
+    fn from(legacy: LegacyPendingRequest) -> Self {
+        let certificate_type = String::from_str(legacy.id.env(), "");
+        PendingRequest {
+            id: legacy.id,
+            issuer: legacy.issuer,
+            recipient: legacy.recipient,
+            metadata: legacy.metadata,
+            proposer: legacy.proposer,
+            approvals: legacy.approvals,
+            rejections: legacy.rejections,
+            rejection_reason: legacy.rejection_reason,
+            created_at: legacy.created_at,
+            expires_at: Deadline::Timestamp(legacy.expires_at),
+            status: legacy.status,
+            certificate_type,
+            executable_at: OptionalDeadline::None,
+            amendment_count: 0,
+            config_version: 0,
+            validity: CertificateValidity::ExpiresAt(legacy.expires_at),
+            transfer_policy: TransferPolicy::FreelyTransferable,
+            metadata_schema: None,
+        }
+    }
+}
+
+impl PendingRequest {
+    /// Decode a stored request, converting entries written in the legacy shape
+    pub fn from_stored(env: &Env, entry: Val) -> Option<Self> {
+        decode_upgraded::<Self, LegacyPendingRequest>(env, entry, "certificate_type")
+    }
+
+    /// Report a pending request past its deadline as `Expired`. Storage keeps `Pending`
+    /// until the request is next acted on.
+    pub fn with_lapsed_expiry(mut self, env: &Env) -> Self {
+        if self.status == RequestStatus::Pending && self.expires_at.has_passed(env) {
+            self.status = RequestStatus::Expired;
+        }
+        self
+    }
+}
+
+/// Ledger close time assumed when converting between ledger and timestamp deadlines
+pub const LEDGER_CLOSE_SECONDS: u64 = 5;
+
+/// A deadline counted either in ledger sequence numbers or in ledger close timestamps.
+///
+/// Configured windows (the admin `proposal_window`, an issuer request window) use the same
+/// type as a span counted from the moment of proposal; proposals and requests store the
+/// resolved absolute deadline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Deadline {
+    Ledger(u32),
+    Timestamp(u64),
+}
+
+impl Deadline {
+    /// A window of whole days, measured in ledger timestamps
+    pub fn days(days: u32) -> Self {
+        Deadline::Timestamp(days as u64 * 24 * 60 * 60)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Deadline::Ledger(ledgers) => *ledgers == 0,
+            Deadline::Timestamp(seconds) => *seconds == 0,
+        }
+    }
+
+    /// Resolve a window into an absolute deadline counted from the current ledger
+    pub fn from_now(&self, env: &Env) -> Self {
+        match self {
+            Deadline::Ledger(ledgers) => {
+                Deadline::Ledger(env.ledger().sequence().saturating_add(*ledgers))
+            }
+            Deadline::Timestamp(seconds) => {
+                Deadline::Timestamp(env.ledger().timestamp().saturating_add(*seconds))
+            }
+        }
+    }
+
+    /// Whether the current ledger is past this absolute deadline
+    pub fn has_passed(&self, env: &Env) -> bool {
+        match self {
+            Deadline::Ledger(sequence) => env.ledger().sequence() > *sequence,
+            Deadline::Timestamp(timestamp) => env.ledger().timestamp() > *timestamp,
+        }
+    }
+
+    /// Whether the current ledger has reached this absolute deadline
+    pub fn is_reached(&self, env: &Env) -> bool {
+        match self {
+            Deadline::Ledger(sequence) => env.ledger().sequence() >= *sequence,
+            Deadline::Timestamp(timestamp) => env.ledger().timestamp() >= *timestamp,
+        }
+    }
+
+    /// Estimated ledger sequence of this absolute deadline, from the current ledger
+    pub fn to_ledger(&self, env: &Env) -> u32 {
+        match self {
+            Deadline::Ledger(sequence) => *sequence,
+            Deadline::Timestamp(timestamp) => {
+                let now = env.ledger().timestamp();
+                let sequence = env.ledger().sequence();
+                if *timestamp >= now {
+                    let ledgers = (*timestamp - now).div_ceil(LEDGER_CLOSE_SECONDS);
+                    sequence.saturating_add(ledgers.min(u32::MAX as u64) as u32)
+                } else {
+                    let ledgers = (now - *timestamp) / LEDGER_CLOSE_SECONDS;
+                    sequence.saturating_sub(ledgers.min(u32::MAX as u64) as u32)
+                }
+            }
+        }
+    }
+
+    /// Estimated close timestamp of this absolute deadline, from the current ledger
+    pub fn to_timestamp(&self, env: &Env) -> u64 {
+        match self {
+            Deadline::Timestamp(timestamp) => *timestamp,
+            Deadline::Ledger(sequence) => {
+                let now = env.ledger().timestamp();
+                let current = env.ledger().sequence();
+                if *sequence >= current {
+                    now.saturating_add((*sequence - current) as u64 * LEDGER_CLOSE_SECONDS)
+                } else {
+                    now.saturating_sub((current - *sequence) as u64 * LEDGER_CLOSE_SECONDS)
+                }
+            }
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OptionalDeadline {
+    None,
+    Some(Deadline),
+}
+
+impl OptionalDeadline {
+    /// An unset deadline never holds anything back
+    pub fn is_reached(&self, env: &Env) -> bool {
+        match self {
+            OptionalDeadline::None => true,
+            OptionalDeadline::Some(deadline) => deadline.is_reached(env),
+        }
+    }
+}
+
+/// Validity of a certificate issued through multisig approval, independent of the
+/// request's own approval deadline
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CertificateValidity {
+    /// The certificate never expires
+    Permanent,
+    /// The certificate expires at a fixed timestamp
+    ExpiresAt(u64),
+    /// The certificate expires the given number of seconds after issuance
+    Duration(u64),
+}
+
+impl CertificateValidity {
+    /// Resolve the certificate expiry for a certificate issued at `issued_at`
+    pub fn expires_at(&self, issued_at: u64) -> Option<u64> {
+        match self {
+            CertificateValidity::Permanent => None,
+            CertificateValidity::ExpiresAt(timestamp) => Some(*timestamp),
+            CertificateValidity::Duration(seconds) => Some(issued_at.saturating_add(*seconds)),
+        }
+    }
+}
+
+/// How in-flight requests are evaluated after the issuer's signer set or threshold changes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignerRotationPolicy {
+    /// Requests keep the rules in force when they were proposed
+    KeepOriginalRules,
+    /// Requests follow the current rules; signatures from removed signers are dropped
+    ReevaluateCurrentRules,
+}
+
+/// Snapshot of a request's fields before an amendment replaced them
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestAmendment {
+    pub amendment: u32,
+    pub previous_metadata: String,
+    pub previous_recipient: Address,
+    pub amended_by: Address,
+    pub amended_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestProposedEvent {
+    pub request_id: String,
+    pub issuer: Address,
+    pub proposer: Address,
+    pub recipient: Address,
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+    pub expires_at: Deadline,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestApprovedEvent {
+    pub request_id: String,
+    pub approver: Address,
+    pub approval_count: u32,
+    pub approval_weight: u32,
+    pub threshold: u32,
+    pub status: RequestStatus,
+    pub executable_at: OptionalDeadline,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestRejectedEvent {
+    pub request_id: String,
+    pub rejector: Address,
+    pub reason: Option<String>,
+    pub status: RequestStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestVetoedEvent {
+    pub request_id: String,
+    pub signer: Address,
+    pub reason: Option<String>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestCancelledEvent {
+    pub request_id: String,
+    pub proposer: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestIssuedEvent {
+    pub request_id: String,
+    pub issuer: Address,
+    pub recipient: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestAmendedEvent {
+    pub request_id: String,
+    pub issuer: Address,
+    pub amendment: u32,
+    pub signers: Vec<Address>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SignatureResult {
@@ -192,21 +888,58 @@ pub struct SignatureResult {
     pub final_status: OptionalRequestStatus,
 }
 
+/// Cursor-based page request shared by all list endpoints. `cursor` is the position in
+/// the underlying index to resume from: 0 for the first page, then the `next_cursor`
+/// returned by the previous page.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Pagination {
-    pub page: u32,
+    pub cursor: u32,
     pub limit: u32,
 }
 
+impl Pagination {
+    /// Entries a single page may load, matching or not; keeps a sparse filter from reading
+    /// past the per-transaction ledger read limit.
+    pub const MAX_SCAN: u32 = 30;
+
+    /// Walk `ids` from the cursor, loading entries until `limit` of them match or
+    /// `MAX_SCAN` have been read. Returns the page and the cursor to resume from, if any
+    /// entries remain; a page can come back short or empty with a cursor still set.
+    pub fn collect<T, F>(&self, env: &Env, ids: &Vec<String>, mut load: F) -> (Vec<T>, Option<u32>)
+    where
+        T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        F: FnMut(String) -> Option<T>,
+    {
+        self.require_limit();
+        let mut data = Vec::<T>::new(env);
+        let mut index = self.cursor;
+        while index < ids.len() {
+            if data.len() >= self.limit || index - self.cursor >= Self::MAX_SCAN {
+                return (data, Some(index));
+            }
+            if let Some(item) = ids.get(index).and_then(&mut load) {
+                data.push_back(item);
+            }
+            index += 1;
+        }
+        (data, None)
+    }
+
+    /// A zero limit would hand back the same cursor forever
+    pub fn require_limit(&self) {
+        if self.limit == 0 {
+            panic!("Pagination limit must be greater than zero");
+        }
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PaginatedResult {
     pub data: Vec<PendingRequest>,
-    pub total: u32,
-    pub page: u32,
     pub limit: u32,
-    pub has_next: bool,
+    pub next_cursor: Option<u32>,
 }
 
 // Batch Verification Types
@@ -228,12 +961,34 @@ pub struct VerificationReport {
     pub results: Vec<VerificationResult>,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerPaginatedResult {
+    pub data: Vec<Address>,
+    pub limit: u32,
+    pub next_cursor: Option<u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegatePaginatedResult {
+    pub data: Vec<Address>,
+    pub limit: u32,
+    pub next_cursor: Option<u32>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CertPaginatedResult {
     pub data: Vec<Certificate>,
-    pub total: u32,
-    pub page: u32,
     pub limit: u32,
-    pub has_next: bool,
+    pub next_cursor: Option<u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferPaginatedResult {
+    pub data: Vec<CertificateTransfer>,
+    pub limit: u32,
+    pub next_cursor: Option<u32>,
 }